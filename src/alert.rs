@@ -1,2 +1,11 @@
 pub(crate) mod alicloud_monitor;
+pub(crate) mod cloudevents;
 pub(crate) mod grafana;
+pub(crate) mod graylog;
+pub(crate) mod heartbeat;
+pub(crate) mod json_webhook;
+pub(crate) mod nagios;
+pub(crate) mod netdata;
+pub(crate) mod smtp;
+pub(crate) mod syslog;
+pub(crate) mod vuln_report;
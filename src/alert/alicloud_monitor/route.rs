@@ -5,16 +5,15 @@ use crate::{
     alert::alicloud_monitor::{
         message::AlertBody,
         transform::{
-            event_level_to_feishu_template_color, threshold_alert_state_to_feishu_template_color,
+            event_level_to_feishu_template_color, event_level_to_notification_severity,
+            threshold_alert_state_to_feishu_template_color,
+            threshold_alert_state_to_notification_status,
+            threshold_trigger_level_to_notification_severity,
         },
     },
-    common::{
-        check_api_key, log_form, AlertDestinations, AlertKeyMap, NotifyResponseEnum, Response,
-    },
-    error::FeishuFailedRequestError,
-    notify::feishu::{
-        api_define::NotifyResponse as FeishuNotifyResponse, post::post as feishu_post,
-    },
+    common::{check_api_key, log_form, AlertKeyMap, Response},
+    error::NotifyFailedRequestError,
+    notify::{notify, Notification, NotificationStatus},
 };
 
 const ALI_CLOUD_MONITOR_HOST: &str = "https://cloudmonitornext.console.aliyun.com";
@@ -23,75 +22,120 @@ pub async fn handle_request(
     api_keys: Vec<AlertKeyMap>,
     body: AlertBody,
 ) -> Result<impl Reply, Rejection> {
-    let mut results = Vec::new();
-    for api_key in api_keys {
-        if api_key.destination == AlertDestinations::Feishu {
-            let template = match body {
-                AlertBody::Threshold(ref body) => {
-                    threshold_alert_state_to_feishu_template_color(&body.alert_state)
-                }
-                AlertBody::Event(ref body) => event_level_to_feishu_template_color(&body.level),
-            };
-            let title = match body {
-                AlertBody::Threshold(ref body) => {
-                    let instance_name = match body.instance_name == "null" {
-                        true => "Instance".to_string(),
-                        false => format!("{} instance", body.instance_name),
-                    };
-                    format!(
-                        "{}({}) {} {}（{}）",
-                        instance_name,
-                        body.alert_name,
-                        body.raw_metric_name,
-                        body.alert_state,
-                        body.cur_value,
-                    )
-                }
-                AlertBody::Event(ref body) => body.name.to_string(),
+    let template = match body {
+        AlertBody::Threshold(ref body) => {
+            threshold_alert_state_to_feishu_template_color(&body.alert_state)
+        }
+        AlertBody::Event(ref body) => event_level_to_feishu_template_color(&body.level),
+    };
+    let title = match body {
+        AlertBody::Threshold(ref body) => {
+            let instance_name = match body.instance_name == "null" {
+                true => "Instance".to_string(),
+                false => format!("{} instance", body.instance_name),
             };
+            format!(
+                "{}({}) {} {}（{}）",
+                instance_name,
+                body.alert_name,
+                body.raw_metric_name,
+                body.alert_state,
+                body.cur_value,
+            )
+        }
+        AlertBody::Event(ref body) => body.name.to_string(),
+    };
 
-            let mut url = format!("{}/newalarm-happen/type:product", ALI_CLOUD_MONITOR_HOST);
-            if let AlertBody::Threshold(ref body) = body {
-                if let Some(group_id) = &body.group_id {
-                    url = format!(
-                        "{}/app-group/{}/alarmRule?ruleName={}",
-                        ALI_CLOUD_MONITOR_HOST, group_id, body.alert_name
-                    );
-                }
-            }
+    let mut url = format!("{}/newalarm-happen/type:product", ALI_CLOUD_MONITOR_HOST);
+    if let AlertBody::Threshold(ref body) = body {
+        if let Some(group_id) = &body.group_id {
+            url = format!(
+                "{}/app-group/{}/alarmRule?ruleName={}",
+                ALI_CLOUD_MONITOR_HOST, group_id, body.alert_name
+            );
+        }
+    }
 
-            let message = match body {
-                AlertBody::Threshold(ref body) => format!(
-                    "Instance: **{}**\nRule Id: {}\nRelated resource: {}\nStatus: **{}**\n{}: {}(**{}**)",
-                    body.instance_name,
-                    body.alert_name,
-                    body.dimensions,
-                    body.alert_state,
-                    body.raw_metric_name,
-                    body.expression,
-                    body.cur_value,
-                ),
-                AlertBody::Event(ref body) => format!(
-                    "**{}**\nInstance: {}\n- level: {}",
-                    body.product, body.instance_name, body.level,
-                ),
+    let message = match body {
+        AlertBody::Threshold(ref body) => format!(
+            "Instance: **{}**\nRule Id: {}\nRelated resource: {}\nStatus: **{}**\n{}: {}(**{}**)",
+            body.instance_name,
+            body.alert_name,
+            body.dimensions,
+            body.alert_state,
+            body.raw_metric_name,
+            body.expression,
+            body.cur_value,
+        ),
+        AlertBody::Event(ref body) => format!(
+            "**{}**\nInstance: {}\n- level: {}",
+            body.product, body.instance_name, body.level,
+        ),
+    };
+    // A recovered threshold alert is at `OK` level, its severity is the one it recovered from.
+    let (status, severity) = match body {
+        AlertBody::Threshold(ref body) => {
+            let status = threshold_alert_state_to_notification_status(&body.alert_state);
+            let level = match status {
+                NotificationStatus::Resolved => &body.pre_trigger_level,
+                NotificationStatus::Firing => &body.trigger_level,
             };
-            match feishu_post(api_key.key, title.clone(), url, message, Some(template)).await {
-                Ok((status, response)) => {
-                    results.push(NotifyResponseEnum::Feishu(FeishuNotifyResponse {
-                        destination: api_key.destination.to_string(),
-                        status,
-                        result: response,
-                    }));
-                }
-                Err(err) => {
-                    return Err(warp::reject::custom(FeishuFailedRequestError {
-                        message: err.to_string(),
-                    }));
-                }
-            }
+            (
+                status,
+                threshold_trigger_level_to_notification_severity(level),
+            )
         }
-    }
+        AlertBody::Event(ref body) => (
+            NotificationStatus::Firing,
+            event_level_to_notification_severity(&body.level),
+        ),
+    };
+    let labels = match body {
+        AlertBody::Threshold(ref body) => vec![
+            ("namespace", body.namespace.clone()),
+            ("metric_name", body.metric_name.clone()),
+            ("rule_id", body.rule_id.clone()),
+            ("instance_name", body.instance_name.clone()),
+            ("dimensions", body.dimensions.clone()),
+        ],
+        AlertBody::Event(ref body) => vec![
+            ("product", body.product.clone()),
+            ("name", body.name.clone()),
+            ("region_id", body.region_id.clone()),
+            ("resource_id", body.resource_id.clone()),
+            ("instance_name", body.instance_name.clone()),
+        ],
+    };
+
+    let notification = Notification {
+        title,
+        url: Some(url),
+        content: message,
+        template: Some(template),
+        status,
+        severity: Some(severity),
+        labels: labels
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect(),
+        fingerprint: Some(match body {
+            AlertBody::Threshold(ref body) => {
+                format!("alicloud-{}-{}", body.rule_id, body.dimensions)
+            }
+            AlertBody::Event(ref body) => format!(
+                "alicloud-{}-{}-{}",
+                body.product, body.name, body.resource_id
+            ),
+        }),
+        raw: serde_json::to_value(&body).ok(),
+        ..Default::default()
+    };
+
+    let results = notify(api_keys, &notification).await.map_err(|err| {
+        warp::reject::custom(NotifyFailedRequestError {
+            message: err.to_string(),
+        })
+    })?;
 
     Ok(warp::reply::json(
         &(Response {
@@ -103,7 +147,7 @@ pub async fn handle_request(
 }
 
 // POST /api/v1/alicloud_monitor/alerts?apiKey=<api-key>,<api-key>
-// apiKey format: "feishu_<API_KEY>,feishu_<API_KEY>"
+// apiKey format: "feishu_<API_KEY>,dingtalk_<ACCESS_TOKEN>[:<SECRET>]"
 pub fn alert() -> BoxedFilter<(impl Reply,)> {
     let log = warp::log::custom(|info| {
         info!(
@@ -1,6 +1,6 @@
 use crate::{
-    alert::alicloud_monitor::message::{EventLevel, ThresholdAlertState},
-    notify::feishu::card::TemplateColor,
+    alert::alicloud_monitor::message::{EventLevel, ThresholdAlertState, ThresholdTriggerLevel},
+    notify::{feishu::card::TemplateColor, NotificationSeverity, NotificationStatus},
 };
 
 pub fn threshold_alert_state_to_feishu_template_color(
@@ -20,3 +20,30 @@ pub fn event_level_to_feishu_template_color(level: &EventLevel) -> TemplateColor
         EventLevel::Info => TemplateColor::Orange,
     }
 }
+
+pub fn threshold_alert_state_to_notification_status(
+    state: &ThresholdAlertState,
+) -> NotificationStatus {
+    match state {
+        ThresholdAlertState::OK => NotificationStatus::Resolved,
+        _ => NotificationStatus::Firing,
+    }
+}
+
+pub fn threshold_trigger_level_to_notification_severity(
+    level: &ThresholdTriggerLevel,
+) -> NotificationSeverity {
+    match level {
+        ThresholdTriggerLevel::Critical => NotificationSeverity::Critical,
+        ThresholdTriggerLevel::Warn => NotificationSeverity::Warning,
+        _ => NotificationSeverity::Info,
+    }
+}
+
+pub fn event_level_to_notification_severity(level: &EventLevel) -> NotificationSeverity {
+    match level {
+        EventLevel::Critical => NotificationSeverity::Critical,
+        EventLevel::Warning => NotificationSeverity::Warning,
+        EventLevel::Info => NotificationSeverity::Info,
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const SPEC_VERSION: &str = "1.0";
+
+// Structured mode, `Content-Type: application/cloudevents+json`:
+// {
+//   "specversion": "1.0",
+//   "id": "45c83279-c8a1-4db6-a703-b3768db93887",
+//   "source": "acs.ecs",
+//   "type": "ecs:Instance:StateChange",
+//   "subject": "acs:ecs:cn-hangzhou:1234567890:instance/i-bp1ecr5go2go1****",
+//   "time": "2022-08-04T07:02:56Z",
+//   "datacontenttype": "application/json",
+//   "data": { "resourceId": "i-bp1ecr5go2go1****", "state": "Stopped" }
+// }
+//
+// Binary mode carries the same attributes as `ce-*` headers, with `data` as the body.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CloudEvent {
+    pub specversion: String,
+    pub id: String,
+    pub source: String,
+    pub r#type: String,
+    pub subject: Option<String>,
+    pub time: Option<String>,
+    pub datacontenttype: Option<String>,
+    pub dataschema: Option<String>,
+    #[serde(default)]
+    pub data: Value,
+}
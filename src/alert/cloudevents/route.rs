@@ -0,0 +1,116 @@
+use serde_json::Value;
+use warp::{
+    filters::BoxedFilter,
+    http::HeaderMap,
+    hyper::{header::CONTENT_TYPE, StatusCode},
+    Filter, Rejection, Reply,
+};
+
+use crate::{
+    alert::cloudevents::{
+        message::{CloudEvent, SPEC_VERSION},
+        transform::cloud_event_to_alert,
+    },
+    common::{check_api_key, AlertKeyMap, Response},
+    error::{FormBodyDeserializeError, InvalidConfigError, NotifyFailedRequestError},
+    notify::notify,
+};
+
+const STRUCTURED_CONTENT_TYPE: &str = "application/cloudevents+json";
+
+fn header(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| {
+            percent_encoding::percent_decode_str(value)
+                .decode_utf8_lossy()
+                .to_string()
+        })
+}
+
+fn binary_event(headers: &HeaderMap, body: &str) -> Result<CloudEvent, String> {
+    let required = |name: &str| header(headers, name).ok_or(format!("missing header `{}`", name));
+    let datacontenttype = header(headers, CONTENT_TYPE.as_str());
+    let data = match datacontenttype {
+        _ if body.is_empty() => Value::Null,
+        Some(ref t) if t.starts_with("application/json") || t.contains("+json") => {
+            serde_json::from_str(body).map_err(|err| err.to_string())?
+        }
+        _ => Value::String(body.to_string()),
+    };
+
+    Ok(CloudEvent {
+        specversion: required("ce-specversion")?,
+        id: required("ce-id")?,
+        source: required("ce-source")?,
+        r#type: required("ce-type")?,
+        subject: header(headers, "ce-subject"),
+        time: header(headers, "ce-time"),
+        datacontenttype,
+        dataschema: header(headers, "ce-dataschema"),
+        data,
+    })
+}
+
+// Accept structured mode (`application/cloudevents+json`) and binary mode (`ce-*` headers).
+fn cloud_event() -> impl Filter<Extract = (CloudEvent,), Error = Rejection> + Copy {
+    warp::header::headers_cloned()
+        .and(warp::body::bytes())
+        .and_then(|headers: HeaderMap, body: bytes::Bytes| async move {
+            let body = std::str::from_utf8(&body).map_err(|err| {
+                warp::reject::custom(FormBodyDeserializeError {
+                    message: err.to_string(),
+                })
+            })?;
+            info!("Received CloudEvent: {}", body);
+
+            let structured = header(&headers, CONTENT_TYPE.as_str())
+                .map(|t| t.starts_with(STRUCTURED_CONTENT_TYPE))
+                .unwrap_or(false);
+            let event = match structured {
+                true => serde_json::from_str::<CloudEvent>(body).map_err(|err| err.to_string()),
+                false => binary_event(&headers, body),
+            }
+            .and_then(|event| match event.specversion.as_str() {
+                SPEC_VERSION => Ok(event),
+                version => Err(format!("unsupported specversion `{}`", version)),
+            });
+
+            event.map_err(|message| warp::reject::custom(FormBodyDeserializeError { message }))
+        })
+}
+
+pub async fn handle_request(
+    api_keys: Vec<AlertKeyMap>,
+    event: CloudEvent,
+) -> Result<impl Reply, Rejection> {
+    let alert = cloud_event_to_alert(&event)
+        .map_err(|message| warp::reject::custom(InvalidConfigError { message }))?;
+
+    let results = notify(api_keys, &alert).await.map_err(|err| {
+        warp::reject::custom(NotifyFailedRequestError {
+            message: err.to_string(),
+        })
+    })?;
+
+    Ok(warp::reply::json(
+        &(Response {
+            code: StatusCode::OK.as_u16(),
+            message: "success".to_string(),
+            data: results,
+        }),
+    ))
+}
+
+// POST /api/v1/cloudevents?apiKey=<api-key>,<api-key>
+// apiKey format: "feishu_<API_KEY>,dingtalk_<ACCESS_TOKEN>[:<SECRET>]"
+pub fn alert() -> BoxedFilter<(impl Reply,)> {
+    warp::post()
+        .and(warp::path!("api" / "v1" / "cloudevents"))
+        .and(warp::body::content_length_limit(1024 * 1024 * 10))
+        .and(check_api_key())
+        .and(cloud_event())
+        .and_then(handle_request)
+        .boxed()
+}
@@ -0,0 +1,61 @@
+use serde_json::Value;
+
+use crate::{
+    alert::{cloudevents::message::CloudEvent, json_webhook::transform::render_alert},
+    config::config,
+    notify::Notification,
+};
+
+fn default_alert(event: &CloudEvent) -> Notification {
+    let mut message = vec![format!("**{}**", event.source)];
+    if let Some(ref subject) = event.subject {
+        message.push(format!("- Subject: {}", subject));
+    }
+    if let Some(ref time) = event.time {
+        message.push(format!("- Time: {}", time));
+    }
+    message.push(format!("- Id: {}", event.id));
+    match event.data {
+        Value::Null => {}
+        Value::String(ref data) => message.push(data.clone()),
+        ref data => message.push(format!(
+            "```\n{}\n```",
+            serde_json::to_string_pretty(data).unwrap_or_default()
+        )),
+    }
+
+    Notification {
+        title: event.r#type.clone(),
+        url: match event.source.starts_with("http://") || event.source.starts_with("https://") {
+            true => Some(event.source.clone()),
+            false => None,
+        },
+        content: message.join("\n"),
+        labels: [
+            ("source".to_string(), event.source.clone()),
+            ("type".to_string(), event.r#type.clone()),
+        ]
+        .into_iter()
+        .collect(),
+        raw: serde_json::to_value(event).ok(),
+        ..Default::default()
+    }
+}
+
+// Render with the webhook mapping routed by the event type prefix, falling back to a plain
+// summary of the event attributes and data.
+pub fn cloud_event_to_alert(event: &CloudEvent) -> Result<Notification, String> {
+    let route = match config().cloudevent_route(&event.r#type) {
+        Some(route) => route,
+        None => return Ok(default_alert(event)),
+    };
+    let webhook = config().webhook(&route.webhook).ok_or_else(|| {
+        format!(
+            "webhook `{}` of CloudEvents route `{}` is not defined",
+            route.webhook, route.type_prefix
+        )
+    })?;
+    let body = serde_json::to_value(event).map_err(|err| err.to_string())?;
+
+    render_alert(webhook, &body)
+}
@@ -1,59 +1,55 @@
 use warp::{filters::BoxedFilter, hyper::StatusCode, Filter, Rejection, Reply};
 
 use crate::{
-    alert::grafana::{message::AlertBody, transform::alert_state_to_feishu_template_color},
-    common::{
-        check_api_key, log_json, AlertDestinations, AlertKeyMap, NotifyResponseEnum, Response,
-    },
-    error::FeishuFailedRequestError,
-    notify::feishu::{
-        api_define::NotifyResponse as FeishuNotifyResponse, post::post as feishu_post,
+    alert::grafana::{
+        message::AlertBody,
+        transform::{
+            alert_state_to_feishu_template_color, alert_state_to_notification_severity,
+            alert_state_to_notification_status,
+        },
     },
+    common::{check_api_key, log_json, AlertKeyMap, Response},
+    error::NotifyFailedRequestError,
+    notify::{notify, Notification},
 };
 
 pub async fn handle_request(
     api_keys: Vec<AlertKeyMap>,
     body: AlertBody,
 ) -> Result<impl Reply, Rejection> {
-    let mut results = Vec::new();
-    for api_key in api_keys {
-        if api_key.destination == AlertDestinations::Feishu {
-            let template = alert_state_to_feishu_template_color(&body.state);
-            let message = body.message.clone().unwrap_or_else(|| "".to_string());
-            let eval_matches = body
-                .eval_matches
-                .iter()
-                .map(|m| format!("- Matric: {}, Value: {}", m.metric, m.value))
-                .collect::<Vec<String>>()
-                .join("\n");
-            let message = format!(
-                "**{}**\nMessage: {}\n{}\n- State: {}",
-                body.rule_name, message, eval_matches, body.state,
-            );
-            match feishu_post(
-                api_key.key,
-                body.title.clone(),
-                body.rule_url.clone().unwrap_or_else(|| "".to_string()),
-                message,
-                Some(template),
-            )
-            .await
-            {
-                Ok((status, response)) => {
-                    results.push(NotifyResponseEnum::Feishu(FeishuNotifyResponse {
-                        destination: api_key.destination.to_string(),
-                        status,
-                        result: response,
-                    }));
-                }
-                Err(err) => {
-                    return Err(warp::reject::custom(FeishuFailedRequestError {
-                        message: err.to_string(),
-                    }));
-                }
-            }
-        }
-    }
+    let message = body.message.clone().unwrap_or_else(|| "".to_string());
+    let eval_matches = body
+        .eval_matches
+        .iter()
+        .map(|m| format!("- Matric: {}, Value: {}", m.metric, m.value))
+        .collect::<Vec<String>>()
+        .join("\n");
+    let notification = Notification {
+        title: body.title.clone(),
+        url: body.rule_url.clone(),
+        content: format!(
+            "**{}**\nMessage: {}\n{}\n- State: {}",
+            body.rule_name, message, eval_matches, body.state,
+        ),
+        template: Some(alert_state_to_feishu_template_color(&body.state)),
+        image: body.image_url.clone(),
+        status: alert_state_to_notification_status(&body.state),
+        severity: Some(alert_state_to_notification_severity(&body.state)),
+        labels: body
+            .tags
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect(),
+        fingerprint: Some(format!("grafana-{}-{}", body.org_id, body.rule_id)),
+        raw: serde_json::to_value(&body).ok(),
+        ..Default::default()
+    };
+
+    let results = notify(api_keys, &notification).await.map_err(|err| {
+        warp::reject::custom(NotifyFailedRequestError {
+            message: err.to_string(),
+        })
+    })?;
 
     Ok(warp::reply::json(
         &(Response {
@@ -65,7 +61,7 @@ pub async fn handle_request(
 }
 
 // POST /api/v1/grafana/alerts?apiKey=<api-key>,<api-key>
-// apiKey format: "feishu_<API_KEY>,feishu_<API_KEY>"
+// apiKey format: "feishu_<API_KEY>,dingtalk_<ACCESS_TOKEN>[:<SECRET>]"
 pub fn alert() -> BoxedFilter<(impl Reply,)> {
     warp::post()
         .and(warp::path!("api" / "v1" / "grafana" / "alerts"))
@@ -1,4 +1,7 @@
-use crate::{alert::grafana::message::AlertState, notify::feishu::card::TemplateColor};
+use crate::{
+    alert::grafana::message::AlertState,
+    notify::{feishu::card::TemplateColor, NotificationSeverity, NotificationStatus},
+};
 
 pub fn alert_state_to_feishu_template_color(state: &AlertState) -> TemplateColor {
     match state {
@@ -10,3 +13,18 @@ pub fn alert_state_to_feishu_template_color(state: &AlertState) -> TemplateColor
         AlertState::Unknown => TemplateColor::Yellow,
     }
 }
+
+pub fn alert_state_to_notification_status(state: &AlertState) -> NotificationStatus {
+    match state {
+        AlertState::OK => NotificationStatus::Resolved,
+        _ => NotificationStatus::Firing,
+    }
+}
+
+pub fn alert_state_to_notification_severity(state: &AlertState) -> NotificationSeverity {
+    match state {
+        AlertState::Alerting | AlertState::NoData => NotificationSeverity::Critical,
+        AlertState::Pending | AlertState::Unknown => NotificationSeverity::Warning,
+        AlertState::Paused | AlertState::OK => NotificationSeverity::Info,
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum::Display;
+
+/*
+{
+  "event_definition_id": "this-is-a-test-notification",
+  "event_definition_type": "test-dummy-v1",
+  "event_definition_title": "Event Definition Test Title",
+  "event_definition_description": "Event Definition Test Description",
+  "job_definition_id": "<unknown>",
+  "job_trigger_id": "<unknown>",
+  "event": {
+    "id": "01DG1MR3WWMB3BH8ATYEYNWWF9",
+    "event_definition_type": "test-dummy-v1",
+    "event_definition_id": "this-is-a-test-notification",
+    "origin_context": "urn:graylog:message:es:testIndex_42:b5e53442-12bb-4374-90ed-0deadbeefbaz",
+    "timestamp": "2019-07-02T13:19:55.354Z",
+    "timestamp_processing": "2019-07-02T13:19:55.354Z",
+    "timerange_start": null,
+    "timerange_end": null,
+    "streams": [],
+    "source_streams": [],
+    "message": "Notification test message triggered from user <admin>",
+    "source": "000000-graylog-server",
+    "key_tuple": ["testkey"],
+    "key": "testkey",
+    "priority": 2,
+    "alert": true,
+    "fields": {
+      "field1": "value1",
+      "field2": "value2"
+    }
+  },
+  "backlog": [
+    {
+      "index": "graylog_0",
+      "message": "Test message",
+      "timestamp": "2019-07-02T13:19:55.354Z",
+      "source": "example.org",
+      "stream_ids": [],
+      "fields": {},
+      "id": "b5e53442-12bb-4374-90ed-0deadbeefbaz"
+    }
+  ]
+}
+*/
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Display, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum EventPriority {
+    Low,
+    Normal,
+    High,
+    Critical,
+    Unknown,
+}
+
+impl From<u8> for EventPriority {
+    fn from(priority: u8) -> Self {
+        match priority {
+            1 => EventPriority::Low,
+            2 => EventPriority::Normal,
+            3 => EventPriority::High,
+            4 => EventPriority::Critical,
+            _ => EventPriority::Unknown,
+        }
+    }
+}
+
+impl From<EventPriority> for u8 {
+    fn from(priority: EventPriority) -> Self {
+        match priority {
+            EventPriority::Low => 1,
+            EventPriority::Normal => 2,
+            EventPriority::High => 3,
+            EventPriority::Critical => 4,
+            EventPriority::Unknown => 0,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Event {
+    pub id: String,
+    pub message: String,
+    pub source: Option<String>,
+    pub timestamp: String,
+    pub priority: EventPriority,
+    pub alert: bool,
+    pub key: Option<String>,
+    #[serde(default)]
+    pub fields: HashMap<String, Value>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BacklogMessage {
+    pub id: Option<String>,
+    pub index: Option<String>,
+    pub message: String,
+    pub timestamp: String,
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AlertBody {
+    pub event_definition_id: String,
+    pub event_definition_type: String,
+    pub event_definition_title: String,
+    pub event_definition_description: Option<String>,
+    pub event: Event,
+    #[serde(default)]
+    pub backlog: Vec<BacklogMessage>,
+}
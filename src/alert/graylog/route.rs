@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use warp::{filters::BoxedFilter, hyper::StatusCode, Filter, Rejection, Reply};
+
+use crate::{
+    alert::graylog::{
+        message::AlertBody,
+        transform::{
+            event_priority_to_feishu_template_color, event_priority_to_notification_severity,
+        },
+    },
+    common::{check_api_key, log_json, AlertKeyMap, Response},
+    error::NotifyFailedRequestError,
+    notify::{notify, Notification},
+};
+
+const DEFAULT_BACKLOG_LIMIT: usize = 5;
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraylogQueryParams {
+    pub backlog_limit: Option<usize>,
+}
+
+fn backlog_note(body: &AlertBody, limit: usize) -> Option<String> {
+    if body.backlog.is_empty() || limit == 0 {
+        return None;
+    }
+
+    let backlog = body
+        .backlog
+        .iter()
+        .take(limit)
+        .map(|m| {
+            format!(
+                "- {} {}: {}",
+                m.timestamp,
+                m.source.as_deref().unwrap_or("-"),
+                m.message
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    Some(format!(
+        "▸ **Backlog** ({} of {})\n{}",
+        body.backlog.len().min(limit),
+        body.backlog.len(),
+        backlog
+    ))
+}
+
+pub async fn handle_request(
+    api_keys: Vec<AlertKeyMap>,
+    query: GraylogQueryParams,
+    body: AlertBody,
+) -> Result<impl Reply, Rejection> {
+    let template = event_priority_to_feishu_template_color(&body.event.priority);
+    let mut fields = body
+        .event
+        .fields
+        .iter()
+        .map(|(k, v)| {
+            let value = v
+                .as_str()
+                .map(str::to_string)
+                .unwrap_or_else(|| v.to_string());
+            format!("- {}: {}", k, value)
+        })
+        .collect::<Vec<String>>();
+    fields.sort();
+    let message = format!(
+        "**{}**\nMessage: {}\n{}\n- Priority: {}\n- Source: {}\n- Timestamp: {}",
+        body.event_definition_title,
+        body.event.message,
+        fields.join("\n"),
+        body.event.priority,
+        body.event.source.as_deref().unwrap_or("-"),
+        body.event.timestamp,
+    );
+
+    let notification = Notification {
+        title: body.event_definition_title.clone(),
+        content: message,
+        template: Some(template),
+        note: backlog_note(&body, query.backlog_limit.unwrap_or(DEFAULT_BACKLOG_LIMIT)),
+        severity: Some(event_priority_to_notification_severity(
+            &body.event.priority,
+        )),
+        labels: body
+            .event
+            .fields
+            .iter()
+            .map(|(k, v)| {
+                let value = v
+                    .as_str()
+                    .map(str::to_string)
+                    .unwrap_or_else(|| v.to_string());
+                (k.clone(), value)
+            })
+            .collect(),
+        fingerprint: Some(match body.event.key {
+            Some(ref key) if !key.is_empty() => {
+                format!("graylog-{}-{}", body.event_definition_id, key)
+            }
+            _ => format!("graylog-{}", body.event_definition_id),
+        }),
+        raw: serde_json::to_value(&body).ok(),
+        ..Default::default()
+    };
+
+    let results = notify(api_keys, &notification).await.map_err(|err| {
+        warp::reject::custom(NotifyFailedRequestError {
+            message: err.to_string(),
+        })
+    })?;
+
+    Ok(warp::reply::json(
+        &(Response {
+            code: StatusCode::OK.as_u16(),
+            message: "success".to_string(),
+            data: results,
+        }),
+    ))
+}
+
+// POST /api/v1/graylog/alerts?apiKey=<api-key>,<api-key>&backlogLimit=<n>
+// apiKey format: "feishu_<API_KEY>,dingtalk_<ACCESS_TOKEN>[:<SECRET>]"
+pub fn alert() -> BoxedFilter<(impl Reply,)> {
+    warp::post()
+        .and(warp::path!("api" / "v1" / "graylog" / "alerts"))
+        .and(check_api_key())
+        .and(warp::query::<GraylogQueryParams>())
+        .and(log_json())
+        .and_then(handle_request)
+        .boxed()
+}
@@ -0,0 +1,23 @@
+use crate::{
+    alert::graylog::message::EventPriority,
+    notify::{feishu::card::TemplateColor, NotificationSeverity},
+};
+
+pub fn event_priority_to_feishu_template_color(priority: &EventPriority) -> TemplateColor {
+    match priority {
+        EventPriority::Low => TemplateColor::Blue,
+        EventPriority::Normal => TemplateColor::Orange,
+        EventPriority::High => TemplateColor::Red,
+        EventPriority::Critical => TemplateColor::Carmine,
+        EventPriority::Unknown => TemplateColor::Grey,
+    }
+}
+
+pub fn event_priority_to_notification_severity(priority: &EventPriority) -> NotificationSeverity {
+    match priority {
+        EventPriority::Critical => NotificationSeverity::Critical,
+        EventPriority::High => NotificationSeverity::Error,
+        EventPriority::Normal => NotificationSeverity::Warning,
+        EventPriority::Low | EventPriority::Unknown => NotificationSeverity::Info,
+    }
+}
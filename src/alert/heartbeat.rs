@@ -0,0 +1,3 @@
+pub(crate) mod message;
+pub(crate) mod monitor;
+pub(crate) mod route;
@@ -0,0 +1,24 @@
+use std::time::Instant;
+
+use strum::Display;
+
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "lowercase")]
+pub enum CheckStatus {
+    New,
+    Up,
+    Down,
+}
+
+#[derive(Display, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeartbeatEvent {
+    Overdue,
+    Failed,
+    Recovered,
+}
+
+#[derive(Debug)]
+pub struct CheckState {
+    pub status: CheckStatus,
+    pub last_ping: Instant,
+}
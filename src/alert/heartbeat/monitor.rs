@@ -0,0 +1,161 @@
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    alert::heartbeat::message::{CheckState, CheckStatus, HeartbeatEvent},
+    common::{parse_api_key, NotifyResponseEnum},
+    config::{config, HeartbeatConfig},
+    notify::{
+        feishu::card::TemplateColor, notify as notify_all, truncate, Notification,
+        NotificationSeverity, NotificationStatus,
+    },
+};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(10);
+const MAX_DETAIL_LENGTH: usize = 4000;
+
+static CHECKS: LazyLock<Mutex<HashMap<String, CheckState>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+// Record a ping, and return the event to notify when the check changes between up and down,
+// with the time of the ping to pass to `notify`.
+pub fn ping(check: &HeartbeatConfig, failed: bool) -> Option<(HeartbeatEvent, Instant)> {
+    let mut checks = CHECKS.lock().unwrap();
+    let state = checks.entry(check.id.clone()).or_insert(CheckState {
+        status: CheckStatus::New,
+        last_ping: Instant::now(),
+    });
+    state.last_ping = Instant::now();
+
+    match (failed, state.status) {
+        (true, CheckStatus::Down) => None,
+        (true, _) => Some((HeartbeatEvent::Failed, state.last_ping)),
+        (false, CheckStatus::Down) => Some((HeartbeatEvent::Recovered, state.last_ping)),
+        (false, _) => {
+            state.status = CheckStatus::Up;
+            None
+        }
+    }
+}
+
+// The check only changes to the status of the event once it is notified, so a failed
+// notification is retried by the next ping or check. It stays as is when pinged again since
+// `last_ping`, which makes the event stale.
+fn settle(check: &HeartbeatConfig, event: HeartbeatEvent, last_ping: Instant) {
+    let mut checks = CHECKS.lock().unwrap();
+    if let Some(state) = checks.get_mut(&check.id) {
+        if state.last_ping == last_ping {
+            state.status = match event {
+                HeartbeatEvent::Recovered => CheckStatus::Up,
+                HeartbeatEvent::Overdue | HeartbeatEvent::Failed => CheckStatus::Down,
+            };
+        }
+    }
+}
+
+pub async fn notify(
+    check: &HeartbeatConfig,
+    event: HeartbeatEvent,
+    last_ping: Instant,
+    detail: Option<String>,
+) -> Result<Vec<NotifyResponseEnum>, Box<dyn std::error::Error>> {
+    let api_keys = parse_api_key(&check.api_key)
+        .map_err(|_| format!("invalid api_key of heartbeat `{}`", check.id))?;
+    let name = check.name.as_deref().unwrap_or(&check.id);
+    let (title, template, status, severity) = match event {
+        HeartbeatEvent::Overdue => (
+            format!("{} missed its heartbeat", name),
+            TemplateColor::Red,
+            NotificationStatus::Firing,
+            NotificationSeverity::Critical,
+        ),
+        HeartbeatEvent::Failed => (
+            format!("{} reported a failure", name),
+            TemplateColor::Red,
+            NotificationStatus::Firing,
+            NotificationSeverity::Critical,
+        ),
+        HeartbeatEvent::Recovered => (
+            format!("{} is back up", name),
+            TemplateColor::Green,
+            NotificationStatus::Resolved,
+            NotificationSeverity::Info,
+        ),
+    };
+    let mut content = vec![
+        format!("Check: **{}**", check.id),
+        format!("Period: {}s, grace: {}s", check.period, check.grace),
+    ];
+    if let Some(detail) = detail.filter(|detail| !detail.is_empty()) {
+        content.push(match detail.chars().count() > MAX_DETAIL_LENGTH {
+            true => format!("{}\n...", truncate(&detail, MAX_DETAIL_LENGTH)),
+            false => detail,
+        });
+    }
+
+    let notification = Notification {
+        title,
+        content: content.join("\n"),
+        template: Some(template),
+        status,
+        severity: Some(severity),
+        labels: [("check".to_string(), check.id.clone())]
+            .into_iter()
+            .collect(),
+        fingerprint: Some(format!("heartbeat-{}", check.id)),
+        ..Default::default()
+    };
+    let results = notify_all(api_keys, &notification).await?;
+    settle(check, event, last_ping);
+    Ok(results)
+}
+
+// Notify the checks that stopped pinging, which become down once notified.
+async fn check_overdue() {
+    let overdue = {
+        let checks = CHECKS.lock().unwrap();
+        config()
+            .heartbeats
+            .iter()
+            .filter_map(|check| {
+                let state = checks.get(&check.id)?;
+                let elapsed = state.last_ping.elapsed();
+                if state.status == CheckStatus::Down
+                    || elapsed <= Duration::from_secs(check.period + check.grace)
+                {
+                    return None;
+                }
+                Some((check, state.last_ping, elapsed))
+            })
+            .collect::<Vec<(&HeartbeatConfig, Instant, Duration)>>()
+    };
+
+    for (check, last_ping, elapsed) in overdue {
+        let detail = format!("Last ping: {} seconds ago", elapsed.as_secs());
+        if let Err(err) = notify(check, HeartbeatEvent::Overdue, last_ping, Some(detail)).await {
+            warn!("Failed to notify overdue heartbeat {}: {}", check.id, err);
+        }
+    }
+}
+
+pub async fn monitor() {
+    {
+        let mut checks = CHECKS.lock().unwrap();
+        for check in &config().heartbeats {
+            checks.entry(check.id.clone()).or_insert(CheckState {
+                status: CheckStatus::New,
+                last_ping: Instant::now(),
+            });
+        }
+    }
+    info!("Monitor {} heartbeat checks", config().heartbeats.len());
+
+    let mut interval = tokio::time::interval(CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        check_overdue().await;
+    }
+}
@@ -0,0 +1,70 @@
+use warp::{filters::BoxedFilter, hyper::StatusCode, Filter, Rejection, Reply};
+
+use crate::{
+    alert::heartbeat::monitor::{notify, ping},
+    common::Response,
+    config::config,
+    error::{FormBodyDeserializeError, NotifyFailedRequestError},
+};
+
+pub async fn handle_request(
+    id: String,
+    failed: bool,
+    body: bytes::Bytes,
+) -> Result<impl Reply, Rejection> {
+    let check = match config().heartbeat(&id) {
+        Some(check) => check,
+        None => return Err(warp::reject::not_found()),
+    };
+    // Validate the body before it counts as a ping.
+    let detail = std::str::from_utf8(&body)
+        .map_err(|err| {
+            warp::reject::custom(FormBodyDeserializeError {
+                message: format!("Heartbeat body is not UTF-8: {}", err),
+            })
+        })?
+        .trim()
+        .to_string();
+    info!("Received heartbeat {}, failed: {}", id, failed);
+
+    let mut results = Vec::new();
+    if let Some((event, last_ping)) = ping(check, failed) {
+        results = notify(check, event, last_ping, Some(detail))
+            .await
+            .map_err(|err| {
+                warp::reject::custom(NotifyFailedRequestError {
+                    message: err.to_string(),
+                })
+            })?;
+    }
+
+    Ok(warp::reply::json(
+        &(Response {
+            code: StatusCode::OK.as_u16(),
+            message: "success".to_string(),
+            data: results,
+        }),
+    ))
+}
+
+// GET|POST /api/v1/heartbeat/<id>
+// GET|POST /api/v1/heartbeat/<id>/fail, a POST body is included in the alert
+// id: one of the `heartbeats` in the config file
+pub fn alert() -> BoxedFilter<(impl Reply,)> {
+    let success = warp::path!("api" / "v1" / "heartbeat" / String).map(|id| (id, false));
+    let fail = warp::path!("api" / "v1" / "heartbeat" / String / "fail").map(|id| (id, true));
+
+    // A GET has no body, and no Content-Length for the limit to check.
+    let get = warp::get().map(bytes::Bytes::new);
+    let post = warp::post()
+        .and(warp::body::content_length_limit(1024 * 1024))
+        .and(warp::body::bytes());
+
+    success
+        .or(fail)
+        .unify()
+        .untuple_one()
+        .and(get.or(post).unify())
+        .and_then(handle_request)
+        .boxed()
+}
@@ -0,0 +1,51 @@
+use serde_json::Value;
+use warp::{filters::BoxedFilter, hyper::StatusCode, Filter, Rejection, Reply};
+
+use crate::{
+    alert::json_webhook::transform::render_alert,
+    common::{check_api_key, log_json, AlertKeyMap, Response},
+    config::config,
+    error::{InvalidConfigError, NotifyFailedRequestError},
+    notify::notify,
+};
+
+pub async fn handle_request(
+    name: String,
+    api_keys: Vec<AlertKeyMap>,
+    body: Value,
+) -> Result<impl Reply, Rejection> {
+    let webhook = match config().webhook(&name) {
+        Some(webhook) => webhook,
+        None => return Err(warp::reject::not_found()),
+    };
+
+    let alert = render_alert(webhook, &body)
+        .map_err(|message| warp::reject::custom(InvalidConfigError { message }))?;
+
+    let results = notify(api_keys, &alert).await.map_err(|err| {
+        warp::reject::custom(NotifyFailedRequestError {
+            message: err.to_string(),
+        })
+    })?;
+
+    Ok(warp::reply::json(
+        &(Response {
+            code: StatusCode::OK.as_u16(),
+            message: "success".to_string(),
+            data: results,
+        }),
+    ))
+}
+
+// POST /api/v1/webhooks/<name>/alerts?apiKey=<api-key>,<api-key>
+// apiKey format: "feishu_<API_KEY>,dingtalk_<ACCESS_TOKEN>[:<SECRET>]"
+// name: one of the `webhooks` in the config file
+pub fn alert() -> BoxedFilter<(impl Reply,)> {
+    warp::post()
+        .and(warp::path!("api" / "v1" / "webhooks" / String / "alerts"))
+        .and(warp::body::content_length_limit(1024 * 1024 * 10))
+        .and(check_api_key())
+        .and(log_json())
+        .and_then(handle_request)
+        .boxed()
+}
@@ -0,0 +1,198 @@
+use std::str::FromStr;
+
+use serde_json::Value;
+
+use crate::{
+    config::JsonWebhookConfig,
+    notify::{feishu::card::TemplateColor, Notification, NotificationSeverity, NotificationStatus},
+    template::{json_path::select, render, value_to_string},
+};
+
+// Objects become one label per entry, arrays one label per item, keyed by the item with an empty
+// value.
+pub fn render_labels(path: &str, body: &Value) -> Result<Vec<(String, String)>, String> {
+    Ok(select(body, path)?
+        .into_iter()
+        .flat_map(|value| match value {
+            Value::Object(map) => map
+                .iter()
+                .map(|(k, v)| (k.clone(), value_to_string(v)))
+                .collect::<Vec<(String, String)>>(),
+            Value::Array(array) => array
+                .iter()
+                .map(|item| (value_to_string(item), "".to_string()))
+                .collect(),
+            _ => vec![(value_to_string(value), "".to_string())],
+        })
+        .collect())
+}
+
+// Rendering against null parses every JSONPath of the mappings without selecting anything, so a
+// broken mapping fails when the config is loaded.
+pub fn check_webhook(webhook: &JsonWebhookConfig) -> Result<(), String> {
+    [
+        Some(&webhook.title),
+        webhook.body.as_ref(),
+        webhook.severity.as_ref(),
+        webhook.status.as_ref(),
+        webhook.url.as_ref(),
+        webhook.fingerprint.as_ref(),
+    ]
+    .into_iter()
+    .flatten()
+    .try_for_each(|expression| render(expression, &Value::Null).map(|_| ()))?;
+    if let Some(ref labels) = webhook.labels {
+        select(&Value::Null, labels)?;
+    }
+    Ok(())
+}
+
+pub fn status_to_feishu_template_color(
+    webhook: &JsonWebhookConfig,
+    status: Option<&str>,
+    severity: Option<&str>,
+) -> Option<TemplateColor> {
+    [status, severity]
+        .into_iter()
+        .flatten()
+        .find_map(|value| webhook.colors.get(value))
+        .and_then(|color| match TemplateColor::from_str(color) {
+            Ok(color) => Some(color),
+            Err(_) => {
+                warn!("Unknown color `{}` in webhook `{}`", color, webhook.name);
+                None
+            }
+        })
+}
+
+pub fn status_to_notification_status(status: &str) -> NotificationStatus {
+    match status.to_lowercase().as_str() {
+        "resolved" | "recovered" | "ok" | "closed" | "clear" => NotificationStatus::Resolved,
+        _ => NotificationStatus::Firing,
+    }
+}
+
+// Accept the names of the common logging and alerting levels besides our own.
+pub fn severity_to_notification_severity(severity: &str) -> Option<NotificationSeverity> {
+    match severity.to_lowercase().as_str() {
+        "critical" | "fatal" | "emergency" | "alert" | "p1" => Some(NotificationSeverity::Critical),
+        "error" | "high" | "major" | "p2" => Some(NotificationSeverity::Error),
+        "warning" | "warn" | "medium" | "minor" | "p3" => Some(NotificationSeverity::Warning),
+        "info" | "low" | "notice" | "debug" | "p4" | "p5" => Some(NotificationSeverity::Info),
+        _ => None,
+    }
+}
+
+// Apply every mapping of the webhook to the body.
+pub fn render_alert(webhook: &JsonWebhookConfig, body: &Value) -> Result<Notification, String> {
+    let render_optional = |expression: &Option<String>| {
+        expression
+            .as_ref()
+            .map(|expression| render(expression, body))
+            .transpose()
+    };
+
+    let title = render(&webhook.title, body)?;
+    let content = render_optional(&webhook.body)?;
+    let severity = render_optional(&webhook.severity)?;
+    let status = render_optional(&webhook.status)?;
+    let url = render_optional(&webhook.url)?;
+    let fingerprint = render_optional(&webhook.fingerprint)?.filter(|f| !f.is_empty());
+    let labels = match webhook.labels {
+        Some(ref labels) => render_labels(labels, body)?,
+        None => Vec::new(),
+    };
+
+    let mut message = vec![content.unwrap_or_default()];
+    if let Some(ref status) = status {
+        message.push(format!("- Status: **{}**", status));
+    }
+    if let Some(ref severity) = severity {
+        message.push(format!("- Severity: **{}**", severity));
+    }
+    message.extend(labels.iter().map(|(k, v)| match v.is_empty() {
+        true => format!("- {}", k),
+        false => format!("- {}: {}", k, v),
+    }));
+
+    Ok(Notification {
+        title,
+        url,
+        content: message.join("\n"),
+        template: status_to_feishu_template_color(webhook, status.as_deref(), severity.as_deref()),
+        status: status
+            .as_deref()
+            .map(status_to_notification_status)
+            .unwrap_or_default(),
+        severity: severity
+            .as_deref()
+            .and_then(severity_to_notification_severity),
+        labels: labels.into_iter().collect(),
+        fingerprint,
+        raw: Some(body.clone()),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn render_labels_of_objects_and_arrays() {
+        let body = json!({ "meta": { "team": "db", "env": "prod" }, "tags": ["a", 1] });
+        let label = |k: &str, v: &str| (k.to_string(), v.to_string());
+        assert_eq!(
+            render_labels("$.meta", &body).unwrap(),
+            vec![label("env", "prod"), label("team", "db")]
+        );
+        assert_eq!(
+            render_labels("$.tags", &body).unwrap(),
+            vec![label("a", ""), label("1", "")]
+        );
+        let body = json!({ "meta": { "url": "http://a: b" } });
+        assert_eq!(
+            render_labels("$.meta", &body).unwrap(),
+            vec![label("url", "http://a: b")]
+        );
+    }
+
+    #[test]
+    fn check_webhook_paths() {
+        let webhook: JsonWebhookConfig = serde_json::from_value(json!({
+            "name": "sentry",
+            "title": "[{{ $.action }}] {{ $.data.issue.title }}",
+            "labels": "$.data.issue.tags[*]"
+        }))
+        .unwrap();
+        assert!(check_webhook(&webhook).is_ok());
+        let webhook = JsonWebhookConfig {
+            severity: Some("{{ level }}".to_string()),
+            ..webhook
+        };
+        assert!(check_webhook(&webhook).is_err());
+    }
+
+    #[test]
+    fn map_status_and_severity() {
+        assert_eq!(
+            status_to_notification_status("Resolved"),
+            NotificationStatus::Resolved
+        );
+        assert_eq!(
+            status_to_notification_status("triggered"),
+            NotificationStatus::Firing
+        );
+        assert_eq!(
+            severity_to_notification_severity("FATAL"),
+            Some(NotificationSeverity::Critical)
+        );
+        assert_eq!(
+            severity_to_notification_severity("p5"),
+            Some(NotificationSeverity::Info)
+        );
+        assert_eq!(severity_to_notification_severity("whatever"), None);
+    }
+}
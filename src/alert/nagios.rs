@@ -0,0 +1,3 @@
+pub(crate) mod message;
+pub(crate) mod route;
+pub(crate) mod transform;
@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+// Fields posted by a notification command, e.g. for Icinga2:
+//
+// curl -X POST "http://notify-bridge:3030/api/v1/nagios/alerts?apiKey=feishu_<API_KEY>" \
+//   --data-urlencode "NOTIFICATIONTYPE=$notification.type$" \
+//   --data-urlencode "HOSTNAME=$host.name$" \
+//   --data-urlencode "SERVICEDESC=$service.name$" \
+//   --data-urlencode "SERVICESTATE=$service.state$" \
+//   --data-urlencode "SERVICEOUTPUT=$service.output$" \
+//   --data-urlencode "LONGDATETIME=$icinga.long_date_time$"
+//
+// Host notifications send HOSTSTATE/HOSTOUTPUT ($host.state$/$host.output$) instead of the
+// SERVICE* fields. Nagios commands use the macro of the same name as each field, e.g.
+// "NOTIFICATIONTYPE=$NOTIFICATIONTYPE$" or "SERVICEDESC=$SERVICEDESC$".
+#[derive(Display, Debug, Deserialize, Serialize, PartialEq, Eq, EnumString)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum NotificationType {
+    Problem,
+    Recovery,
+    Acknowledgement,
+    FlappingStart,
+    // Icinga2 sends FLAPPINGEND.
+    #[serde(alias = "FLAPPINGEND")]
+    #[strum(serialize = "FLAPPINGSTOP", serialize = "FLAPPINGEND")]
+    FlappingStop,
+    FlappingDisabled,
+    DowntimeStart,
+    DowntimeEnd,
+    // Icinga2 sends DOWNTIMEREMOVED.
+    #[serde(alias = "DOWNTIMEREMOVED")]
+    #[strum(serialize = "DOWNTIMECANCELLED", serialize = "DOWNTIMEREMOVED")]
+    DowntimeCancelled,
+    Custom,
+    // Types of other monitoring engines are still notified.
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Display, Debug, Deserialize, Serialize, PartialEq, Eq, EnumString)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum ServiceState {
+    OK,
+    Warning,
+    Critical,
+    Unknown,
+}
+
+#[derive(Display, Debug, Deserialize, Serialize, PartialEq, Eq, EnumString)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum HostState {
+    Up,
+    Down,
+    Unreachable,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ServiceAlertBody {
+    #[serde(rename = "NOTIFICATIONTYPE")]
+    pub notification_type: NotificationType,
+    #[serde(rename = "HOSTNAME")]
+    pub host_name: String,
+    #[serde(rename = "HOSTADDRESS")]
+    pub host_address: Option<String>,
+    #[serde(rename = "SERVICEDESC")]
+    pub service_desc: String,
+    #[serde(rename = "SERVICESTATE")]
+    pub service_state: ServiceState,
+    #[serde(rename = "SERVICEOUTPUT")]
+    pub service_output: String,
+    #[serde(rename = "LONGDATETIME")]
+    pub long_date_time: String,
+    #[serde(rename = "NOTIFICATIONAUTHOR")]
+    pub notification_author: Option<String>,
+    #[serde(rename = "NOTIFICATIONCOMMENT")]
+    pub notification_comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HostAlertBody {
+    #[serde(rename = "NOTIFICATIONTYPE")]
+    pub notification_type: NotificationType,
+    #[serde(rename = "HOSTNAME")]
+    pub host_name: String,
+    #[serde(rename = "HOSTADDRESS")]
+    pub host_address: Option<String>,
+    #[serde(rename = "HOSTSTATE")]
+    pub host_state: HostState,
+    #[serde(rename = "HOSTOUTPUT")]
+    pub host_output: String,
+    #[serde(rename = "LONGDATETIME")]
+    pub long_date_time: String,
+    #[serde(rename = "NOTIFICATIONAUTHOR")]
+    pub notification_author: Option<String>,
+    #[serde(rename = "NOTIFICATIONCOMMENT")]
+    pub notification_comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum AlertBody {
+    Service(ServiceAlertBody),
+    Host(HostAlertBody),
+}
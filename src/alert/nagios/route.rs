@@ -0,0 +1,154 @@
+use reqwest::StatusCode;
+use warp::{filters::BoxedFilter, Filter, Rejection, Reply};
+
+use crate::{
+    alert::nagios::{
+        message::AlertBody,
+        transform::{
+            host_state_to_feishu_template_color, host_state_to_notification_severity,
+            host_state_to_notification_status, notification_type_is_informational,
+            service_state_to_feishu_template_color, service_state_to_notification_severity,
+            service_state_to_notification_status,
+        },
+    },
+    common::{check_api_key, log_form, AlertKeyMap, Response},
+    error::NotifyFailedRequestError,
+    notify::{notify, Notification},
+};
+
+pub async fn handle_request(
+    api_keys: Vec<AlertKeyMap>,
+    body: AlertBody,
+) -> Result<impl Reply, Rejection> {
+    let template = match body {
+        AlertBody::Service(ref body) => {
+            service_state_to_feishu_template_color(&body.notification_type, &body.service_state)
+        }
+        AlertBody::Host(ref body) => {
+            host_state_to_feishu_template_color(&body.notification_type, &body.host_state)
+        }
+    };
+    let title = match body {
+        AlertBody::Service(ref body) => format!(
+            "[{}] {}/{} is {}",
+            body.notification_type, body.host_name, body.service_desc, body.service_state,
+        ),
+        AlertBody::Host(ref body) => format!(
+            "[{}] Host {} is {}",
+            body.notification_type, body.host_name, body.host_state,
+        ),
+    };
+
+    let (notification_author, notification_comment) = match body {
+        AlertBody::Service(ref body) => (&body.notification_author, &body.notification_comment),
+        AlertBody::Host(ref body) => (&body.notification_author, &body.notification_comment),
+    };
+    let comment = match (notification_author, notification_comment) {
+        (Some(author), Some(comment)) if !comment.is_empty() => {
+            format!("\nComment: {} ({})", comment, author)
+        }
+        _ => "".to_string(),
+    };
+
+    let message = match body {
+        AlertBody::Service(ref body) => format!(
+            "Host: **{}**{}\nService: **{}**\nState: **{}**\nOutput: {}\nTime: {}{}",
+            body.host_name,
+            body.host_address
+                .as_ref()
+                .map(|address| format!(" ({})", address))
+                .unwrap_or_default(),
+            body.service_desc,
+            body.service_state,
+            body.service_output,
+            body.long_date_time,
+            comment,
+        ),
+        AlertBody::Host(ref body) => format!(
+            "Host: **{}**{}\nState: **{}**\nOutput: {}\nTime: {}{}",
+            body.host_name,
+            body.host_address
+                .as_ref()
+                .map(|address| format!(" ({})", address))
+                .unwrap_or_default(),
+            body.host_state,
+            body.host_output,
+            body.long_date_time,
+            comment,
+        ),
+    };
+    let (notification_type, status, severity, labels) = match body {
+        AlertBody::Service(ref body) => (
+            &body.notification_type,
+            service_state_to_notification_status(&body.service_state),
+            service_state_to_notification_severity(&body.service_state),
+            vec![
+                ("host_name", body.host_name.clone()),
+                ("service_desc", body.service_desc.clone()),
+            ],
+        ),
+        AlertBody::Host(ref body) => (
+            &body.notification_type,
+            host_state_to_notification_status(&body.host_state),
+            host_state_to_notification_severity(&body.host_state),
+            vec![("host_name", body.host_name.clone())],
+        ),
+    };
+
+    let notification = Notification {
+        title,
+        content: message,
+        template: Some(template),
+        status,
+        severity: Some(severity),
+        informational: notification_type_is_informational(notification_type),
+        labels: labels
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v))
+            .collect(),
+        fingerprint: Some(match body {
+            AlertBody::Service(ref body) => {
+                format!("nagios-{}-{}", body.host_name, body.service_desc)
+            }
+            AlertBody::Host(ref body) => format!("nagios-{}", body.host_name),
+        }),
+        raw: serde_json::to_value(&body).ok(),
+        ..Default::default()
+    };
+
+    let results = notify(api_keys, &notification).await.map_err(|err| {
+        warp::reject::custom(NotifyFailedRequestError {
+            message: err.to_string(),
+        })
+    })?;
+
+    Ok(warp::reply::json(
+        &(Response {
+            code: StatusCode::OK.as_u16(),
+            message: "success".to_string(),
+            data: results,
+        }),
+    ))
+}
+
+// POST /api/v1/nagios/alerts?apiKey=<api-key>,<api-key>
+// apiKey format: "feishu_<API_KEY>,dingtalk_<ACCESS_TOKEN>[:<SECRET>]"
+pub fn alert() -> BoxedFilter<(impl Reply,)> {
+    let log = warp::log::custom(|info| {
+        info!(
+            "method: {}, path: {}, status: {}",
+            info.method(),
+            info.path(),
+            info.status(),
+        );
+    });
+
+    warp::post()
+        .and(warp::path!("api" / "v1" / "nagios" / "alerts"))
+        .and(warp::body::content_length_limit(1024 * 1024 * 10))
+        .and(check_api_key())
+        .and(log_form())
+        .and_then(handle_request)
+        .with(log)
+        .boxed()
+}
@@ -0,0 +1,145 @@
+use crate::{
+    alert::nagios::message::{HostState, NotificationType, ServiceState},
+    notify::{feishu::card::TemplateColor, NotificationSeverity, NotificationStatus},
+};
+
+pub fn service_state_to_feishu_template_color(
+    notification_type: &NotificationType,
+    state: &ServiceState,
+) -> TemplateColor {
+    if *notification_type == NotificationType::Acknowledgement {
+        return TemplateColor::Blue;
+    }
+    match state {
+        ServiceState::OK => TemplateColor::Green,
+        ServiceState::Warning => TemplateColor::Orange,
+        ServiceState::Critical => TemplateColor::Red,
+        ServiceState::Unknown => TemplateColor::Grey,
+    }
+}
+
+pub fn host_state_to_feishu_template_color(
+    notification_type: &NotificationType,
+    state: &HostState,
+) -> TemplateColor {
+    if *notification_type == NotificationType::Acknowledgement {
+        return TemplateColor::Blue;
+    }
+    match state {
+        HostState::Up => TemplateColor::Green,
+        HostState::Down => TemplateColor::Red,
+        HostState::Unreachable => TemplateColor::Grey,
+    }
+}
+
+// The state, not the type, tells whether the alert is over, e.g. a DOWNTIMEEND of an OK service.
+pub fn service_state_to_notification_status(state: &ServiceState) -> NotificationStatus {
+    match state {
+        ServiceState::OK => NotificationStatus::Resolved,
+        _ => NotificationStatus::Firing,
+    }
+}
+
+pub fn host_state_to_notification_status(state: &HostState) -> NotificationStatus {
+    match state {
+        HostState::Up => NotificationStatus::Resolved,
+        _ => NotificationStatus::Firing,
+    }
+}
+
+// Only problems and recoveries change the state of the alert, the other types such as
+// acknowledgements, flapping or downtimes are informational.
+pub fn notification_type_is_informational(notification_type: &NotificationType) -> bool {
+    !matches!(
+        notification_type,
+        NotificationType::Problem | NotificationType::Recovery
+    )
+}
+
+pub fn service_state_to_notification_severity(state: &ServiceState) -> NotificationSeverity {
+    match state {
+        ServiceState::Critical => NotificationSeverity::Critical,
+        ServiceState::Warning | ServiceState::Unknown => NotificationSeverity::Warning,
+        ServiceState::OK => NotificationSeverity::Info,
+    }
+}
+
+pub fn host_state_to_notification_severity(state: &HostState) -> NotificationSeverity {
+    match state {
+        HostState::Down => NotificationSeverity::Critical,
+        HostState::Unreachable => NotificationSeverity::Error,
+        HostState::Up => NotificationSeverity::Info,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alert::nagios::message::AlertBody;
+
+    fn parse(form: &str) -> AlertBody {
+        serde_urlencoded::from_str(form).unwrap()
+    }
+
+    #[test]
+    fn parse_service_and_host_forms() {
+        match parse(
+            "NOTIFICATIONTYPE=FLAPPINGEND&HOSTNAME=db-01&SERVICEDESC=disk&SERVICESTATE=OK\
+             &SERVICEOUTPUT=ok&LONGDATETIME=now",
+        ) {
+            AlertBody::Service(body) => {
+                assert_eq!(body.notification_type, NotificationType::FlappingStop);
+                assert_eq!(body.service_state, ServiceState::OK);
+                assert_eq!(body.host_address, None);
+            }
+            AlertBody::Host(_) => panic!("parsed as a host notification"),
+        }
+        match parse(
+            "NOTIFICATIONTYPE=CUSTOMTYPE&HOSTNAME=db-01&HOSTSTATE=DOWN&HOSTOUTPUT=timeout\
+             &LONGDATETIME=now",
+        ) {
+            AlertBody::Host(body) => {
+                assert_eq!(body.notification_type, NotificationType::Other);
+                assert_eq!(body.host_state, HostState::Down);
+            }
+            AlertBody::Service(_) => panic!("parsed as a service notification"),
+        }
+    }
+
+    #[test]
+    fn status_from_state() {
+        assert_eq!(
+            service_state_to_notification_status(&ServiceState::OK),
+            NotificationStatus::Resolved
+        );
+        assert_eq!(
+            service_state_to_notification_status(&ServiceState::Unknown),
+            NotificationStatus::Firing
+        );
+        assert_eq!(
+            host_state_to_notification_status(&HostState::Up),
+            NotificationStatus::Resolved
+        );
+        assert_eq!(
+            host_state_to_notification_status(&HostState::Unreachable),
+            NotificationStatus::Firing
+        );
+    }
+
+    #[test]
+    fn informational_types() {
+        assert!(!notification_type_is_informational(
+            &NotificationType::Problem
+        ));
+        assert!(!notification_type_is_informational(
+            &NotificationType::Recovery
+        ));
+        assert!(notification_type_is_informational(
+            &NotificationType::DowntimeEnd
+        ));
+        assert!(notification_type_is_informational(
+            &NotificationType::Acknowledgement
+        ));
+        assert!(notification_type_is_informational(&NotificationType::Other));
+    }
+}
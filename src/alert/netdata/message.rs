@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+// Netdata Cloud webhook integration:
+// {
+//   "message": "Alert critical - System RAM in use",
+//   "alert": "ram_in_use",
+//   "info": "memory utilization",
+//   "chart": "system.ram",
+//   "context": "system.ram",
+//   "space": "All nodes",
+//   "family": "ram",
+//   "class": "Utilization",
+//   "severity": "critical",
+//   "date": "2022-08-04T07:02:56.000Z",
+//   "duration": "1 min 0 sec",
+//   "node": "netdata-parent",
+//   "additional_active_critical_alerts": 0,
+//   "additional_active_warning_alerts": 0,
+//   "alert_url": "https://app.netdata.cloud/spaces/..."
+// }
+//
+// Agent `alarm-notify.sh`, with `custom_sender()` in `health_alarm_notify.conf`:
+// curl -X POST "http://notify-bridge:3030/api/v1/netdata/alerts?apiKey=feishu_<API_KEY>" \
+//   -H "Content-Type: application/json" \
+//   -d "{\"host\": \"${host}\", \"chart\": \"${chart}\", \"alarm\": \"${name}\", \
+//        \"status\": \"${status}\", \"value\": \"${value_string}\", \
+//        \"duration\": \"${duration_txt}\", \"info\": \"${info}\", \"url\": \"${goto_url}\"}"
+#[derive(Display, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum AlarmStatus {
+    #[serde(alias = "critical")]
+    Critical,
+    #[serde(alias = "warning")]
+    Warning,
+    #[serde(alias = "clear")]
+    Clear,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CloudAlertBody {
+    pub message: String,
+    pub alert: String,
+    pub info: Option<String>,
+    pub chart: String,
+    pub context: Option<String>,
+    pub space: Option<String>,
+    pub severity: AlarmStatus,
+    pub date: Option<String>,
+    pub duration: Option<String>,
+    pub node: Option<String>,
+    pub alert_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AgentAlertBody {
+    pub host: String,
+    pub chart: String,
+    pub alarm: String,
+    pub status: AlarmStatus,
+    pub value: Option<String>,
+    pub duration: Option<String>,
+    pub info: Option<String>,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum AlertBody {
+    Cloud(CloudAlertBody),
+    Agent(AgentAlertBody),
+}
@@ -0,0 +1,125 @@
+use warp::{filters::BoxedFilter, hyper::StatusCode, Filter, Rejection, Reply};
+
+use crate::{
+    alert::netdata::{
+        message::AlertBody,
+        transform::{
+            alarm_status_to_feishu_template_color, alarm_status_to_notification_severity,
+            alarm_status_to_notification_status,
+        },
+    },
+    common::{check_api_key, log_json, AlertKeyMap, Response},
+    error::NotifyFailedRequestError,
+    notify::{notify, Notification},
+};
+
+const NETDATA_CLOUD_HOST: &str = "https://app.netdata.cloud";
+
+pub async fn handle_request(
+    api_keys: Vec<AlertKeyMap>,
+    body: AlertBody,
+) -> Result<impl Reply, Rejection> {
+    let status = match body {
+        AlertBody::Cloud(ref body) => &body.severity,
+        AlertBody::Agent(ref body) => &body.status,
+    };
+    let title = match body {
+        AlertBody::Cloud(ref body) => body.message.clone(),
+        AlertBody::Agent(ref body) => {
+            format!("{} {} on {}", body.alarm, body.status, body.host)
+        }
+    };
+    let url = match body {
+        AlertBody::Cloud(ref body) => Some(
+            body.alert_url
+                .clone()
+                .unwrap_or_else(|| NETDATA_CLOUD_HOST.to_string()),
+        ),
+        AlertBody::Agent(ref body) => body.url.clone(),
+    };
+    let message = match body {
+        AlertBody::Cloud(ref body) => format!(
+            "**{}**\nNode: {}\nChart: {}\nStatus: **{}**\nDuration: {}\nInfo: {}\nSpace: {}",
+            body.alert,
+            body.node.as_deref().unwrap_or("-"),
+            body.chart,
+            body.severity,
+            body.duration.as_deref().unwrap_or("-"),
+            body.info.as_deref().unwrap_or("-"),
+            body.space.as_deref().unwrap_or("-"),
+        ),
+        AlertBody::Agent(ref body) => format!(
+            "**{}**\nHost: {}\nChart: {}\nStatus: **{}**\nValue: **{}**\nDuration: {}\nInfo: {}",
+            body.alarm,
+            body.host,
+            body.chart,
+            body.status,
+            body.value.as_deref().unwrap_or("-"),
+            body.duration.as_deref().unwrap_or("-"),
+            body.info.as_deref().unwrap_or("-"),
+        ),
+    };
+    let labels = match body {
+        AlertBody::Cloud(ref body) => vec![
+            ("alert", Some(body.alert.clone())),
+            ("chart", Some(body.chart.clone())),
+            ("node", body.node.clone()),
+            ("space", body.space.clone()),
+        ],
+        AlertBody::Agent(ref body) => vec![
+            ("alarm", Some(body.alarm.clone())),
+            ("chart", Some(body.chart.clone())),
+            ("host", Some(body.host.clone())),
+        ],
+    };
+    let notification = Notification {
+        title,
+        url,
+        content: message,
+        template: Some(alarm_status_to_feishu_template_color(status)),
+        status: alarm_status_to_notification_status(status),
+        severity: Some(alarm_status_to_notification_severity(status)),
+        labels: labels
+            .into_iter()
+            .filter_map(|(k, v)| v.map(|v| (k.to_string(), v)))
+            .collect(),
+        fingerprint: Some(match body {
+            AlertBody::Cloud(ref body) => format!(
+                "netdata-{}-{}-{}",
+                body.node.as_deref().unwrap_or(""),
+                body.chart,
+                body.alert
+            ),
+            AlertBody::Agent(ref body) => {
+                format!("netdata-{}-{}-{}", body.host, body.chart, body.alarm)
+            }
+        }),
+        raw: serde_json::to_value(&body).ok(),
+        ..Default::default()
+    };
+
+    let results = notify(api_keys, &notification).await.map_err(|err| {
+        warp::reject::custom(NotifyFailedRequestError {
+            message: err.to_string(),
+        })
+    })?;
+
+    Ok(warp::reply::json(
+        &(Response {
+            code: StatusCode::OK.as_u16(),
+            message: "success".to_string(),
+            data: results,
+        }),
+    ))
+}
+
+// POST /api/v1/netdata/alerts?apiKey=<api-key>,<api-key>
+// apiKey format: "feishu_<API_KEY>,dingtalk_<ACCESS_TOKEN>[:<SECRET>]"
+pub fn alert() -> BoxedFilter<(impl Reply,)> {
+    warp::post()
+        .and(warp::path!("api" / "v1" / "netdata" / "alerts"))
+        .and(check_api_key())
+        .and(log_json())
+        .and_then(handle_request)
+        .boxed()
+}
@@ -0,0 +1,28 @@
+use crate::{
+    alert::netdata::message::AlarmStatus,
+    notify::{feishu::card::TemplateColor, NotificationSeverity, NotificationStatus},
+};
+
+pub fn alarm_status_to_feishu_template_color(status: &AlarmStatus) -> TemplateColor {
+    match status {
+        AlarmStatus::Critical => TemplateColor::Red,
+        AlarmStatus::Warning => TemplateColor::Orange,
+        AlarmStatus::Clear => TemplateColor::Green,
+        AlarmStatus::Unknown => TemplateColor::Grey,
+    }
+}
+
+pub fn alarm_status_to_notification_status(status: &AlarmStatus) -> NotificationStatus {
+    match status {
+        AlarmStatus::Clear => NotificationStatus::Resolved,
+        _ => NotificationStatus::Firing,
+    }
+}
+
+pub fn alarm_status_to_notification_severity(status: &AlarmStatus) -> NotificationSeverity {
+    match status {
+        AlarmStatus::Critical => NotificationSeverity::Critical,
+        AlarmStatus::Warning => NotificationSeverity::Warning,
+        AlarmStatus::Clear | AlarmStatus::Unknown => NotificationSeverity::Info,
+    }
+}
@@ -0,0 +1,6 @@
+#[derive(Debug)]
+pub struct Email {
+    pub from: String,
+    pub subject: String,
+    pub body: String,
+}
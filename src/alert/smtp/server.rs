@@ -0,0 +1,228 @@
+use std::{io, sync::Arc, time::Duration};
+
+use tokio::{
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader},
+    net::{TcpListener, TcpStream},
+    sync::Semaphore,
+    time::timeout,
+};
+
+use crate::{
+    alert::smtp::transform::{parse_email, recipient_to_api_keys},
+    common::AlertKeyMap,
+    config::SmtpListenerConfig,
+    notify::{notify, Notification},
+};
+
+const SERVER_NAME: &str = "notify-bridge";
+
+// Text lines are at most 1000 octets with the CRLF (RFC 5321 4.5.3.1.6).
+const MAX_LINE_LENGTH: u64 = 1000;
+
+async fn reply<W: AsyncWrite + Unpin>(writer: &mut W, line: &str) -> io::Result<()> {
+    writer.write_all(format!("{}\r\n", line).as_bytes()).await
+}
+
+// Read a line into `buffer`, never buffering more than `MAX_LINE_LENGTH` bytes: the rest of a
+// longer line is discarded and `Some(false)` returned. `None` at the end of the stream.
+async fn read_line<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    buffer: &mut Vec<u8>,
+) -> io::Result<Option<bool>> {
+    buffer.clear();
+    if (&mut *reader)
+        .take(MAX_LINE_LENGTH)
+        .read_until(b'\n', buffer)
+        .await?
+        == 0
+    {
+        return Ok(None);
+    }
+    if buffer.ends_with(b"\n") || (buffer.len() as u64) < MAX_LINE_LENGTH {
+        return Ok(Some(true));
+    }
+
+    let mut rest = Vec::new();
+    loop {
+        rest.clear();
+        let read = (&mut *reader)
+            .take(MAX_LINE_LENGTH)
+            .read_until(b'\n', &mut rest)
+            .await?;
+        if read == 0 || rest.ends_with(b"\n") {
+            return Ok(Some(false));
+        }
+    }
+}
+
+// A client that sends no full line within `idle` fails the connection.
+async fn read_line_within<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    buffer: &mut Vec<u8>,
+    idle: Duration,
+) -> io::Result<Option<bool>> {
+    timeout(idle, read_line(reader, buffer))
+        .await
+        .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "idle timeout"))?
+}
+
+// `MAIL FROM:<a@b> SIZE=1024` => `a@b`
+fn command_address(argument: &str) -> Option<String> {
+    let (_, rest) = argument.split_once(':')?;
+    let rest = rest.trim();
+    let address = match rest.strip_prefix('<') {
+        Some(rest) => &rest[..rest.find('>')?],
+        None => rest.split_whitespace().next().unwrap_or(""),
+    };
+    Some(address.to_string())
+}
+
+async fn deliver(raw: Vec<u8>, api_keys: Vec<AlertKeyMap>) {
+    let email = match parse_email(&raw) {
+        Ok(email) => email,
+        Err(err) => {
+            warn!("Can not parse email: {}", err);
+            return;
+        }
+    };
+    info!("Received email from {}: {}", email.from, email.subject);
+
+    let notification = Notification {
+        title: email.subject,
+        content: format!("From: {}\n{}", email.from, email.body),
+        labels: [("from".to_string(), email.from)].into_iter().collect(),
+        ..Default::default()
+    };
+    match notify(api_keys, &notification).await {
+        Ok(results) => info!("Email notified: {:?}", results),
+        Err(err) => warn!("Failed to notify of email: {}", err),
+    }
+}
+
+async fn handle_connection(stream: TcpStream, config: &SmtpListenerConfig) -> io::Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    let idle = Duration::from_secs(config.idle_timeout);
+    reply(&mut writer, &format!("220 {} ESMTP ready", SERVER_NAME)).await?;
+
+    let mut api_keys = Vec::new();
+    let mut buffer = Vec::new();
+    loop {
+        match read_line_within(&mut reader, &mut buffer, idle).await? {
+            None => return Ok(()),
+            Some(false) => {
+                reply(&mut writer, "500 Line too long").await?;
+                continue;
+            }
+            Some(true) => {}
+        }
+        let line = String::from_utf8_lossy(&buffer);
+        let command = line.trim_end();
+        let (verb, argument) = command.split_once(' ').unwrap_or((command, ""));
+
+        match verb.to_ascii_uppercase().as_str() {
+            "HELO" => reply(&mut writer, &format!("250 {}", SERVER_NAME)).await?,
+            "EHLO" => {
+                reply(&mut writer, &format!("250-{}", SERVER_NAME)).await?;
+                reply(
+                    &mut writer,
+                    &format!("250-SIZE {}", config.max_message_size),
+                )
+                .await?;
+                reply(&mut writer, "250 8BITMIME").await?;
+            }
+            "MAIL" => {
+                api_keys.clear();
+                reply(&mut writer, "250 OK").await?;
+            }
+            "RCPT" => {
+                let keys = command_address(argument)
+                    .and_then(|address| recipient_to_api_keys(&address, config.domain.as_deref()));
+                match keys {
+                    Some(keys) => {
+                        api_keys.extend(keys);
+                        reply(&mut writer, "250 OK").await?;
+                    }
+                    None => reply(&mut writer, "550 No destination for recipient").await?,
+                }
+            }
+            "DATA" => {
+                if api_keys.is_empty() {
+                    reply(&mut writer, "554 No valid recipients").await?;
+                    continue;
+                }
+                reply(&mut writer, "354 End data with <CR><LF>.<CR><LF>").await?;
+
+                let mut data = Vec::new();
+                let mut line_too_long = false;
+                loop {
+                    match read_line_within(&mut reader, &mut buffer, idle).await? {
+                        None => return Ok(()),
+                        Some(false) => line_too_long = true,
+                        Some(true) => {}
+                    }
+                    if buffer == b".\r\n" || buffer == b".\n" {
+                        break;
+                    }
+                    let unstuffed = match buffer.starts_with(b"..") {
+                        true => &buffer[1..],
+                        false => &buffer[..],
+                    };
+                    if data.len() <= config.max_message_size {
+                        data.extend_from_slice(unstuffed);
+                    }
+                }
+
+                if line_too_long {
+                    reply(&mut writer, "500 Line too long").await?;
+                } else if data.len() > config.max_message_size {
+                    reply(&mut writer, "552 Message size exceeds limit").await?;
+                } else {
+                    reply(&mut writer, "250 OK").await?;
+                    tokio::spawn(deliver(data, std::mem::take(&mut api_keys)));
+                }
+            }
+            "RSET" => {
+                api_keys.clear();
+                reply(&mut writer, "250 OK").await?;
+            }
+            "NOOP" => reply(&mut writer, "250 OK").await?,
+            "QUIT" => {
+                reply(&mut writer, &format!("221 {} closing", SERVER_NAME)).await?;
+                return Ok(());
+            }
+            _ => reply(&mut writer, "502 Command not implemented").await?,
+        }
+    }
+}
+
+pub async fn serve(config: &'static SmtpListenerConfig) {
+    let listener = match TcpListener::bind(("0.0.0.0", config.port)).await {
+        Ok(listener) => listener,
+        Err(err) => {
+            error!("Failed to listen SMTP on port {}: {}", config.port, err);
+            return;
+        }
+    };
+    info!("SMTP listener on port {}", config.port);
+
+    let connections = Arc::new(Semaphore::new(config.max_connections));
+    loop {
+        // Clients beyond `max_connections` wait in the listen backlog.
+        let permit = match connections.clone().acquire_owned().await {
+            Ok(permit) => permit,
+            Err(_) => return,
+        };
+        match listener.accept().await {
+            Ok((stream, address)) => {
+                tokio::spawn(async move {
+                    if let Err(err) = handle_connection(stream, config).await {
+                        warn!("SMTP connection from {} failed: {}", address, err);
+                    }
+                    drop(permit);
+                });
+            }
+            Err(err) => warn!("Failed to accept SMTP connection: {}", err),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use mailparse::{parse_mail, DispositionType, MailHeaderMap, MailParseError, ParsedMail};
+
+use crate::{
+    alert::smtp::message::Email,
+    common::{parse_api_key, AlertKeyMap},
+    notify::truncate,
+};
+
+const MAX_BODY_LENGTH: usize = 4000;
+
+fn find_part<'a, 'b>(mail: &'b ParsedMail<'a>, mimetype: &str) -> Option<&'b ParsedMail<'a>> {
+    if mail.get_content_disposition().disposition == DispositionType::Attachment {
+        return None;
+    }
+    if mail.subparts.is_empty() {
+        return (mail.ctype.mimetype == mimetype).then_some(mail);
+    }
+    mail.subparts
+        .iter()
+        .find_map(|part| find_part(part, mimetype))
+}
+
+// Remove the element with its content, e.g. `<style>...</style>`.
+fn remove_element(html: &str, name: &str) -> String {
+    let lower = html.to_ascii_lowercase();
+    let (open, close) = (format!("<{}", name), format!("</{}>", name));
+    let mut result = String::new();
+    let mut position = 0;
+    while let Some(start) = lower[position..].find(&open) {
+        let start = position + start;
+        result.push_str(&html[position..start]);
+        position = match lower[start..].find(&close) {
+            Some(end) => start + end + close.len(),
+            None => html.len(),
+        };
+    }
+    result.push_str(&html[position..]);
+    result
+}
+
+pub fn strip_html(html: &str) -> String {
+    let html = remove_element(&remove_element(html, "script"), "style");
+
+    let mut text = String::new();
+    let mut rest = html.as_str();
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let end = match rest[start..].find('>') {
+            Some(end) => start + end,
+            None => {
+                rest = "";
+                break;
+            }
+        };
+        let tag = rest[start + 1..end]
+            .trim_start_matches('/')
+            .to_ascii_lowercase();
+        let name = tag.split(|c: char| c.is_whitespace() || c == '/').next();
+        if let Some("br" | "p" | "div" | "tr" | "li" | "h1" | "h2" | "h3" | "h4" | "table") = name {
+            text.push('\n');
+        }
+        rest = &rest[end + 1..];
+    }
+    text.push_str(rest);
+
+    let text = text
+        .replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&");
+
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+// text/plain is preferred, HTML is stripped to text.
+pub fn parse_email(raw: &[u8]) -> Result<Email, MailParseError> {
+    let mail = parse_mail(raw)?;
+    let subject = mail
+        .headers
+        .get_first_value("Subject")
+        .unwrap_or_else(|| "(no subject)".to_string());
+    let from = mail.headers.get_first_value("From").unwrap_or_default();
+
+    let body = if let Some(part) = find_part(&mail, "text/plain") {
+        part.get_body()?.trim().to_string()
+    } else if let Some(part) = find_part(&mail, "text/html") {
+        strip_html(&part.get_body()?)
+    } else {
+        "".to_string()
+    };
+    let body = match body.chars().count() > MAX_BODY_LENGTH {
+        true => format!("{}\n...", truncate(&body, MAX_BODY_LENGTH)),
+        false => body,
+    };
+
+    Ok(Email {
+        from,
+        subject,
+        body,
+    })
+}
+
+// `ops+feishu_<API_KEY>@bridge` maps to `feishu_<API_KEY>`.
+pub fn recipient_to_api_keys(address: &str, domain: Option<&str>) -> Option<Vec<AlertKeyMap>> {
+    let (local, address_domain) = address.rsplit_once('@')?;
+    if let Some(domain) = domain {
+        if !address_domain.eq_ignore_ascii_case(domain) {
+            return None;
+        }
+    }
+    let (_, api_key) = local.split_once('+')?;
+    parse_api_key(api_key).ok()
+}
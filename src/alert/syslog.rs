@@ -0,0 +1,4 @@
+pub(crate) mod message;
+pub(crate) mod rule;
+pub(crate) mod server;
+pub(crate) mod transform;
@@ -0,0 +1,101 @@
+use serde::Serialize;
+use strum::{Display, EnumString};
+
+// RFC 5424:
+// <165>1 2003-10-11T22:14:15.003Z mymachine.example.com evntslog - ID47 [exampleSDID@32473 iut="3"] An application event log entry...
+//
+// RFC 3164:
+// <34>Oct 11 22:14:15 mymachine su[230]: 'su root' failed for lonvick on /dev/pts/8
+#[derive(Display, Debug, Clone, Copy, Serialize, PartialEq, Eq, EnumString)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum Facility {
+    Kern,
+    User,
+    Mail,
+    Daemon,
+    Auth,
+    Syslog,
+    Lpr,
+    News,
+    Uucp,
+    Cron,
+    Authpriv,
+    Ftp,
+    Ntp,
+    Security,
+    Console,
+    Clock,
+    Local0,
+    Local1,
+    Local2,
+    Local3,
+    Local4,
+    Local5,
+    Local6,
+    Local7,
+}
+
+impl Facility {
+    pub fn from_code(code: u8) -> Option<Self> {
+        use Facility::*;
+        [
+            Kern, User, Mail, Daemon, Auth, Syslog, Lpr, News, Uucp, Cron, Authpriv, Ftp, Ntp,
+            Security, Console, Clock, Local0, Local1, Local2, Local3, Local4, Local5, Local6,
+            Local7,
+        ]
+        .get(code as usize)
+        .copied()
+    }
+}
+
+// Ordered from the most severe, so `severity <= threshold` selects the severe ones.
+#[derive(Display, Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord, EnumString)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum Severity {
+    #[strum(to_string = "emergency", serialize = "emerg")]
+    Emergency,
+    Alert,
+    #[strum(to_string = "critical", serialize = "crit")]
+    Critical,
+    #[strum(to_string = "error", serialize = "err")]
+    Error,
+    #[strum(to_string = "warning", serialize = "warn")]
+    Warning,
+    Notice,
+    #[strum(to_string = "informational", serialize = "info")]
+    Informational,
+    Debug,
+}
+
+impl Severity {
+    pub fn from_code(code: u8) -> Option<Self> {
+        use Severity::*;
+        [
+            Emergency,
+            Alert,
+            Critical,
+            Error,
+            Warning,
+            Notice,
+            Informational,
+            Debug,
+        ]
+        .get(code as usize)
+        .copied()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SyslogMessage {
+    pub facility: Facility,
+    pub severity: Severity,
+    pub timestamp: Option<String>,
+    pub hostname: String,
+    pub app_name: Option<String>,
+    pub proc_id: Option<String>,
+    pub msg_id: Option<String>,
+    pub structured_data: Option<String>,
+    pub message: String,
+}
@@ -0,0 +1,139 @@
+use std::{
+    str::FromStr,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use regex::Regex;
+
+use crate::{
+    alert::syslog::message::{Facility, Severity, SyslogMessage},
+    common::{parse_api_key, AlertKeyMap},
+    config::SyslogRuleConfig,
+};
+
+#[derive(Debug)]
+pub struct SyslogRule {
+    pub name: String,
+    pub api_keys: Vec<AlertKeyMap>,
+    pub facilities: Vec<Facility>,
+    pub severity: Option<Severity>,
+    pub hostname: Option<Regex>,
+    pub pattern: Option<Regex>,
+    pub throttle: Duration,
+    // When the rule last notified, and how many matches were dropped since.
+    throttled: Mutex<(Option<Instant>, u64)>,
+}
+
+impl SyslogRule {
+    pub fn compile(config: &SyslogRuleConfig) -> Result<Self, String> {
+        let regex = |expression: &Option<String>| {
+            expression
+                .as_deref()
+                .map(Regex::new)
+                .transpose()
+                .map_err(|err| format!("invalid regex in syslog rule `{}`: {}", config.name, err))
+        };
+
+        Ok(SyslogRule {
+            name: config.name.clone(),
+            api_keys: parse_api_key(&config.api_key)
+                .map_err(|_| format!("invalid api_key in syslog rule `{}`", config.name))?,
+            facilities: config
+                .facilities
+                .iter()
+                .map(|facility| {
+                    Facility::from_str(facility).map_err(|_| {
+                        format!(
+                            "unknown facility `{}` in syslog rule `{}`",
+                            facility, config.name
+                        )
+                    })
+                })
+                .collect::<Result<Vec<Facility>, String>>()?,
+            severity: config
+                .severity
+                .as_deref()
+                .map(Severity::from_str)
+                .transpose()
+                .map_err(|_| format!("unknown severity in syslog rule `{}`", config.name))?,
+            hostname: regex(&config.hostname)?,
+            pattern: regex(&config.pattern)?,
+            throttle: Duration::from_secs(config.throttle),
+            throttled: Mutex::new((None, 0)),
+        })
+    }
+
+    // A match may notify once per `throttle`, returning how many matches were dropped before it.
+    pub fn admit(&self, now: Instant) -> Option<u64> {
+        let mut throttled = self.throttled.lock().unwrap();
+        let (last, dropped) = &mut *throttled;
+        match last {
+            Some(last) if now.saturating_duration_since(*last) < self.throttle => {
+                *dropped += 1;
+                None
+            }
+            _ => {
+                *last = Some(now);
+                Some(std::mem::take(dropped))
+            }
+        }
+    }
+
+    pub fn matches(&self, message: &SyslogMessage) -> bool {
+        (self.facilities.is_empty() || self.facilities.contains(&message.facility))
+            && self
+                .severity
+                .map_or(true, |threshold| message.severity <= threshold)
+            && self
+                .hostname
+                .as_ref()
+                .map_or(true, |hostname| hostname.is_match(&message.hostname))
+            && self
+                .pattern
+                .as_ref()
+                .map_or(true, |pattern| pattern.is_match(&message.message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(throttle: u64) -> SyslogRule {
+        SyslogRule::compile(&SyslogRuleConfig {
+            name: "switches".to_string(),
+            api_key: "feishu_abc".to_string(),
+            facilities: vec![],
+            severity: None,
+            hostname: Some("^sw-".to_string()),
+            pattern: None,
+            throttle,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn throttle_matches() {
+        let rule = rule(60);
+        let start = Instant::now();
+        assert_eq!(rule.admit(start), Some(0));
+        assert_eq!(rule.admit(start + Duration::from_secs(10)), None);
+        assert_eq!(rule.admit(start + Duration::from_secs(59)), None);
+        assert_eq!(rule.admit(start + Duration::from_secs(60)), Some(2));
+        assert_eq!(rule.admit(start + Duration::from_secs(61)), None);
+
+        let rule = self::rule(0);
+        assert_eq!(rule.admit(start), Some(0));
+        assert_eq!(rule.admit(start), Some(0));
+    }
+
+    #[test]
+    fn reject_invalid_regex() {
+        let config = SyslogRuleConfig {
+            hostname: Some("(".to_string()),
+            ..serde_json::from_str(r#"{ "name": "bad", "api_key": "feishu_abc" }"#).unwrap()
+        };
+        assert!(SyslogRule::compile(&config).is_err());
+    }
+}
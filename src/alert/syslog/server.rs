@@ -0,0 +1,213 @@
+use std::{net::SocketAddr, sync::Arc, time::Instant};
+
+use tokio::{
+    io::{AsyncBufReadExt, AsyncReadExt, BufReader},
+    net::{TcpListener, TcpStream, UdpSocket},
+};
+
+use crate::{
+    alert::syslog::{
+        message::SyslogMessage,
+        rule::SyslogRule,
+        transform::{
+            parse_syslog, severity_to_feishu_template_color, severity_to_notification_severity,
+        },
+    },
+    config::SyslogConfig,
+    notify::{notify as notify_all, Notification},
+};
+
+// RFC 5425 limits a message to 8 KiB unless configured otherwise, leave room for bigger ones.
+const MAX_MESSAGE_SIZE: usize = 64 * 1024;
+
+// Digits of `MAX_MESSAGE_SIZE` and the space after them.
+const MAX_FRAME_LENGTH_PREFIX: u64 = 6;
+
+async fn notify(rule: &SyslogRule, message: &SyslogMessage, dropped: u64) {
+    let title = format!(
+        "[{}] {} {}",
+        message.severity,
+        message.hostname,
+        message.app_name.as_deref().unwrap_or(""),
+    );
+    let mut content = vec![message.message.clone()];
+    content.push(format!("- Facility: {}", message.facility));
+    if let Some(ref timestamp) = message.timestamp {
+        content.push(format!("- Time: {}", timestamp));
+    }
+    if let Some(ref structured_data) = message.structured_data {
+        content.push(format!("- Data: {}", structured_data));
+    }
+    content.push(format!("- Rule: {}", rule.name));
+    if dropped > 0 {
+        content.push(format!("- Throttled: {} earlier matches", dropped));
+    }
+
+    let notification = Notification {
+        title: title.trim_end().to_string(),
+        content: content.join("\n"),
+        template: Some(severity_to_feishu_template_color(&message.severity)),
+        severity: Some(severity_to_notification_severity(&message.severity)),
+        labels: [
+            ("facility", Some(message.facility.to_string())),
+            ("hostname", Some(message.hostname.clone())),
+            ("app_name", message.app_name.clone()),
+            ("rule", Some(rule.name.clone())),
+        ]
+        .into_iter()
+        .filter_map(|(k, v)| v.map(|v| (k.to_string(), v)))
+        .collect(),
+        raw: serde_json::to_value(message).ok(),
+        ..Default::default()
+    };
+    match notify_all(rule.api_keys.clone(), &notification).await {
+        Ok(results) => info!("Syslog notified: {:?}", results),
+        Err(err) => warn!("Failed to notify of syslog: {}", err),
+    }
+}
+
+async fn handle_line(line: String, peer: SocketAddr, rules: Arc<Vec<SyslogRule>>) {
+    let message = match parse_syslog(&line, &peer.ip().to_string()) {
+        Some(message) => message,
+        None => {
+            debug!("Ignore invalid syslog from {}: {}", peer, line);
+            return;
+        }
+    };
+
+    for rule in rules.iter().filter(|rule| rule.matches(&message)) {
+        match rule.admit(Instant::now()) {
+            Some(dropped) => notify(rule, &message, dropped).await,
+            None => debug!("Throttle syslog from {} for rule {}", peer, rule.name),
+        }
+    }
+}
+
+async fn serve_udp(port: u16, rules: Arc<Vec<SyslogRule>>) -> std::io::Result<()> {
+    let socket = UdpSocket::bind(("0.0.0.0", port)).await?;
+    info!("Syslog UDP listener on port {}", port);
+
+    let mut buffer = vec![0; MAX_MESSAGE_SIZE];
+    loop {
+        let (size, peer) = match socket.recv_from(&mut buffer).await {
+            Ok(received) => received,
+            Err(err) => {
+                warn!("Failed to receive syslog datagram: {}", err);
+                continue;
+            }
+        };
+        let line = String::from_utf8_lossy(&buffer[..size]).to_string();
+        tokio::spawn(handle_line(line, peer, rules.clone()));
+    }
+}
+
+// RFC 6587 framing, either octet counting (`<length> <message>`) or newline delimited.
+async fn handle_tcp_connection(
+    stream: TcpStream,
+    peer: SocketAddr,
+    rules: Arc<Vec<SyslogRule>>,
+) -> std::io::Result<()> {
+    let mut reader = BufReader::new(stream);
+    loop {
+        let octet_counting = match reader.fill_buf().await? {
+            [] => return Ok(()),
+            buffer => buffer[0].is_ascii_digit(),
+        };
+
+        let line = if octet_counting {
+            let mut length = Vec::new();
+            (&mut reader)
+                .take(MAX_FRAME_LENGTH_PREFIX)
+                .read_until(b' ', &mut length)
+                .await?;
+            // The length is checked before allocating, a longer frame closes the connection.
+            let length = length
+                .strip_suffix(b" ")
+                .and_then(|length| std::str::from_utf8(length).ok())
+                .and_then(|length| length.parse::<usize>().ok())
+                .filter(|length| *length <= MAX_MESSAGE_SIZE)
+                .ok_or_else(|| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid frame length")
+                })?;
+            let mut message = vec![0; length];
+            reader.read_exact(&mut message).await?;
+            message
+        } else {
+            let mut message = Vec::new();
+            (&mut reader)
+                .take(MAX_MESSAGE_SIZE as u64)
+                .read_until(b'\n', &mut message)
+                .await?;
+            // Drop the rest of a longer line, never buffering more than `MAX_MESSAGE_SIZE`.
+            if message.len() == MAX_MESSAGE_SIZE && !message.ends_with(b"\n") {
+                let mut rest = Vec::new();
+                loop {
+                    rest.clear();
+                    let read = (&mut reader)
+                        .take(MAX_MESSAGE_SIZE as u64)
+                        .read_until(b'\n', &mut rest)
+                        .await?;
+                    if read == 0 || rest.ends_with(b"\n") {
+                        break;
+                    }
+                }
+            }
+            message
+        };
+
+        let line = String::from_utf8_lossy(&line).to_string();
+        tokio::spawn(handle_line(line, peer, rules.clone()));
+    }
+}
+
+async fn serve_tcp(port: u16, rules: Arc<Vec<SyslogRule>>) -> std::io::Result<()> {
+    let listener = TcpListener::bind(("0.0.0.0", port)).await?;
+    info!("Syslog TCP listener on port {}", port);
+
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(err) => {
+                warn!("Failed to accept syslog connection: {}", err);
+                continue;
+            }
+        };
+        let rules = rules.clone();
+        tokio::spawn(async move {
+            if let Err(err) = handle_tcp_connection(stream, peer, rules).await {
+                warn!("Syslog connection from {} failed: {}", peer, err);
+            }
+        });
+    }
+}
+
+pub async fn serve(config: &'static SyslogConfig) {
+    let rules = match config
+        .rules
+        .iter()
+        .map(SyslogRule::compile)
+        .collect::<Result<Vec<SyslogRule>, String>>()
+    {
+        Ok(rules) => Arc::new(rules),
+        Err(err) => {
+            error!("Failed to start syslog listeners: {}", err);
+            return;
+        }
+    };
+
+    if let Some(port) = config.udp_port {
+        let rules = rules.clone();
+        tokio::spawn(async move {
+            if let Err(err) = serve_udp(port, rules).await {
+                error!("Syslog UDP listener on port {} failed: {}", port, err);
+            }
+        });
+    }
+    if let Some(port) = config.tcp_port {
+        tokio::spawn(async move {
+            if let Err(err) = serve_tcp(port, rules).await {
+                error!("Syslog TCP listener on port {} failed: {}", port, err);
+            }
+        });
+    }
+}
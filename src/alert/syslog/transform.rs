@@ -0,0 +1,254 @@
+use crate::{
+    alert::syslog::message::{Facility, Severity, SyslogMessage},
+    notify::{feishu::card::TemplateColor, NotificationSeverity},
+};
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+pub fn severity_to_feishu_template_color(severity: &Severity) -> TemplateColor {
+    match severity {
+        Severity::Emergency | Severity::Alert => TemplateColor::Carmine,
+        Severity::Critical | Severity::Error => TemplateColor::Red,
+        Severity::Warning => TemplateColor::Orange,
+        Severity::Notice => TemplateColor::Yellow,
+        Severity::Informational => TemplateColor::Blue,
+        Severity::Debug => TemplateColor::Grey,
+    }
+}
+
+pub fn severity_to_notification_severity(severity: &Severity) -> NotificationSeverity {
+    match severity {
+        Severity::Emergency | Severity::Alert | Severity::Critical => {
+            NotificationSeverity::Critical
+        }
+        Severity::Error => NotificationSeverity::Error,
+        Severity::Warning => NotificationSeverity::Warning,
+        Severity::Notice | Severity::Informational | Severity::Debug => NotificationSeverity::Info,
+    }
+}
+
+fn nil_value(field: &str) -> Option<String> {
+    match field {
+        "-" | "" => None,
+        _ => Some(field.to_string()),
+    }
+}
+
+// `<165>rest` => (local4, notice, "rest")
+fn parse_pri(line: &str) -> Option<(Facility, Severity, &str)> {
+    let rest = line.strip_prefix('<')?;
+    let end = rest.find('>')?;
+    if end == 0 || end > 3 {
+        return None;
+    }
+    let pri = rest[..end].parse::<u8>().ok()?;
+    Some((
+        Facility::from_code(pri / 8)?,
+        Severity::from_code(pri % 8)?,
+        &rest[end + 1..],
+    ))
+}
+
+// Split `[id a="1"][id b="]"] message` into the structured data and the message.
+fn split_structured_data(rest: &str) -> (Option<String>, &str) {
+    if let Some(message) = rest.strip_prefix('-') {
+        return (None, message);
+    }
+
+    let (mut in_quote, mut escaped, mut in_element) = (false, false, false);
+    for (index, c) in rest.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quote => escaped = true,
+            '"' if in_element => in_quote = !in_quote,
+            '[' if !in_quote => in_element = true,
+            ']' if !in_quote => {
+                in_element = false;
+                if !rest[index + 1..].starts_with('[') {
+                    return (Some(rest[..=index].to_string()), &rest[index + 1..]);
+                }
+            }
+            _ if !in_element => return (None, rest),
+            _ => {}
+        }
+    }
+    (nil_value(rest), "")
+}
+
+fn parse_rfc5424(
+    facility: Facility,
+    severity: Severity,
+    rest: &str,
+    peer: &str,
+) -> Option<SyslogMessage> {
+    let mut fields = rest.splitn(7, ' ');
+    if fields.next()? != "1" {
+        return None;
+    }
+    let timestamp = nil_value(fields.next()?);
+    let hostname = nil_value(fields.next()?).unwrap_or_else(|| peer.to_string());
+    let app_name = nil_value(fields.next()?);
+    let proc_id = nil_value(fields.next()?);
+    let msg_id = nil_value(fields.next()?);
+    let (structured_data, message) = split_structured_data(fields.next().unwrap_or("-"));
+
+    Some(SyslogMessage {
+        facility,
+        severity,
+        timestamp,
+        hostname,
+        app_name,
+        proc_id,
+        msg_id,
+        structured_data,
+        message: message
+            .trim_start()
+            .trim_start_matches('\u{feff}')
+            .to_string(),
+    })
+}
+
+// BSD syslog is loosely followed by devices, so every part except the PRI is optional.
+fn parse_rfc3164(facility: Facility, severity: Severity, rest: &str, peer: &str) -> SyslogMessage {
+    let (timestamp, mut rest) = match rest.get(..15) {
+        Some(timestamp)
+            if MONTHS.iter().any(|month| timestamp.starts_with(month))
+                && rest[15..].starts_with(' ') =>
+        {
+            (Some(timestamp.to_string()), rest[16..].trim_start())
+        }
+        _ => (None, rest),
+    };
+
+    let is_tag = |token: &str| token.ends_with(':') || token.contains('[');
+    let mut hostname = peer.to_string();
+    if timestamp.is_some() {
+        if let Some((token, after)) = rest.split_once(' ') {
+            if !is_tag(token) {
+                hostname = token.to_string();
+                rest = after;
+            }
+        }
+    }
+
+    let (mut app_name, mut proc_id) = (None, None);
+    if let Some((tag, message)) = rest.split_once(": ") {
+        if !tag.is_empty() && !tag.contains(' ') {
+            match tag.split_once('[') {
+                Some((name, pid)) => {
+                    app_name = Some(name.to_string());
+                    proc_id = Some(pid.trim_end_matches(']').to_string());
+                }
+                None => app_name = Some(tag.to_string()),
+            }
+            rest = message;
+        }
+    }
+
+    SyslogMessage {
+        facility,
+        severity,
+        timestamp,
+        hostname,
+        app_name,
+        proc_id,
+        msg_id: None,
+        structured_data: None,
+        message: rest.to_string(),
+    }
+}
+
+pub fn parse_syslog(line: &str, peer: &str) -> Option<SyslogMessage> {
+    let line = line.trim_end_matches(|c| c == '\r' || c == '\n' || c == '\0');
+    let (facility, severity, rest) = parse_pri(line)?;
+    parse_rfc5424(facility, severity, rest, peer)
+        .or_else(|| Some(parse_rfc3164(facility, severity, rest, peer)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pri_values() {
+        let (facility, severity, rest) = parse_pri("<165>rest").unwrap();
+        assert_eq!(
+            (facility, severity, rest),
+            (Facility::Local4, Severity::Notice, "rest")
+        );
+        assert!(parse_pri("165>rest").is_none());
+        assert!(parse_pri("<>rest").is_none());
+        assert!(parse_pri("<1650>rest").is_none());
+        assert!(parse_pri("<192>rest").is_none());
+    }
+
+    #[test]
+    fn parse_rfc5424_message() {
+        let message = parse_syslog(
+            "<34>1 2003-10-11T22:14:15.003Z mymachine.example.com su - ID47 \
+             [exampleSDID@32473 iut=\"3\" eventSource=\"App]\"][meta a=\"1\"] \u{feff}'su root' failed\r\n",
+            "10.0.0.1",
+        )
+        .unwrap();
+        assert_eq!(message.facility, Facility::Auth);
+        assert_eq!(message.severity, Severity::Critical);
+        assert_eq!(
+            message.timestamp.as_deref(),
+            Some("2003-10-11T22:14:15.003Z")
+        );
+        assert_eq!(message.hostname, "mymachine.example.com");
+        assert_eq!(message.app_name.as_deref(), Some("su"));
+        assert_eq!(message.proc_id, None);
+        assert_eq!(message.msg_id.as_deref(), Some("ID47"));
+        assert_eq!(
+            message.structured_data.as_deref(),
+            Some("[exampleSDID@32473 iut=\"3\" eventSource=\"App]\"][meta a=\"1\"]")
+        );
+        assert_eq!(message.message, "'su root' failed");
+    }
+
+    #[test]
+    fn parse_rfc5424_nil_values() {
+        let message = parse_syslog("<14>1 - - - - - -", "10.0.0.1").unwrap();
+        assert_eq!(message.timestamp, None);
+        assert_eq!(message.hostname, "10.0.0.1");
+        assert_eq!(message.app_name, None);
+        assert_eq!(message.structured_data, None);
+        assert_eq!(message.message, "");
+    }
+
+    #[test]
+    fn parse_rfc3164_message() {
+        let message = parse_syslog(
+            "<187>Oct 11 22:14:15 sw-core-01 ifmgr[1234]: %LINK-3-UPDOWN: Gi0/1 down",
+            "10.0.0.1",
+        )
+        .unwrap();
+        assert_eq!(message.facility, Facility::Local7);
+        assert_eq!(message.severity, Severity::Error);
+        assert_eq!(message.timestamp.as_deref(), Some("Oct 11 22:14:15"));
+        assert_eq!(message.hostname, "sw-core-01");
+        assert_eq!(message.app_name.as_deref(), Some("ifmgr"));
+        assert_eq!(message.proc_id.as_deref(), Some("1234"));
+        assert_eq!(message.message, "%LINK-3-UPDOWN: Gi0/1 down");
+    }
+
+    #[test]
+    fn parse_rfc3164_without_header() {
+        let message = parse_syslog("<13>sshd: session opened", "10.0.0.1").unwrap();
+        assert_eq!(message.timestamp, None);
+        assert_eq!(message.hostname, "10.0.0.1");
+        assert_eq!(message.app_name.as_deref(), Some("sshd"));
+        assert_eq!(message.message, "session opened");
+
+        let message = parse_syslog("<13>Oct  1 08:00:00 kernel: oops", "10.0.0.1").unwrap();
+        assert_eq!(message.timestamp.as_deref(), Some("Oct  1 08:00:00"));
+        assert_eq!(message.hostname, "10.0.0.1");
+        assert_eq!(message.app_name.as_deref(), Some("kernel"));
+        assert_eq!(message.message, "oops");
+
+        assert!(parse_syslog("no pri", "10.0.0.1").is_none());
+    }
+}
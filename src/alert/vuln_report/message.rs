@@ -0,0 +1,175 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter};
+
+#[derive(
+    Display, Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, EnumIter,
+)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum Severity {
+    #[serde(alias = "UNASSIGNED")]
+    Unknown,
+    Info,
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+// Trivy JSON report (`trivy image --format json`):
+// {
+//   "SchemaVersion": 2,
+//   "ArtifactName": "alpine:3.10",
+//   "ArtifactType": "container_image",
+//   "Results": [
+//     {
+//       "Target": "alpine:3.10 (alpine 3.10.9)",
+//       "Class": "os-pkgs",
+//       "Type": "alpine",
+//       "Vulnerabilities": [
+//         {
+//           "VulnerabilityID": "CVE-2021-36159",
+//           "PkgName": "apk-tools",
+//           "InstalledVersion": "2.10.6-r0",
+//           "FixedVersion": "2.10.7-r0",
+//           "PrimaryURL": "https://avd.aquasec.com/nvd/cve-2021-36159",
+//           "Title": "libfetch before 2021-07-26 ...",
+//           "Severity": "CRITICAL"
+//         }
+//       ]
+//     }
+//   ]
+// }
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TrivyVulnerability {
+    #[serde(rename = "VulnerabilityID")]
+    pub vulnerability_id: String,
+    pub pkg_name: String,
+    pub installed_version: Option<String>,
+    pub fixed_version: Option<String>,
+    #[serde(rename = "PrimaryURL")]
+    pub primary_url: Option<String>,
+    pub title: Option<String>,
+    pub severity: Severity,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TrivyResult {
+    pub target: String,
+    pub class: Option<String>,
+    pub r#type: Option<String>,
+    #[serde(default)]
+    pub vulnerabilities: Option<Vec<TrivyVulnerability>>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TrivyReport {
+    pub schema_version: u8,
+    pub artifact_name: String,
+    pub artifact_type: Option<String>,
+    #[serde(default)]
+    pub results: Option<Vec<TrivyResult>>,
+}
+
+// Dependency-Track `NEW_VULNERABILITY` webhook:
+// {
+//   "notification": {
+//     "level": "INFORMATIONAL",
+//     "scope": "PORTFOLIO",
+//     "group": "NEW_VULNERABILITY",
+//     "timestamp": "2018-08-27T23:26:22.961",
+//     "title": "New Vulnerability Identified",
+//     "content": "",
+//     "subject": {
+//       "component": {
+//         "uuid": "4d5cd8df-cff7-4212-a038-91ae4ab79396",
+//         "group": "apache",
+//         "name": "axis",
+//         "version": "1.4",
+//         "purl": "pkg:maven/apache/axis@1.4"
+//       },
+//       "vulnerability": {
+//         "uuid": "941a93f5-e06b-4304-84de-4d788eeb4969",
+//         "vulnId": "CVE-2018-8032",
+//         "source": "NVD",
+//         "title": "...",
+//         "description": "Apache Axis 1.x up to and including 1.4 is vulnerable to ...",
+//         "severity": "MEDIUM"
+//       },
+//       "affectedProjects": [
+//         {
+//           "uuid": "6fb1820f-5280-4577-ac51-40124aabe307",
+//           "name": "Acme Example",
+//           "version": "1.0.0"
+//         }
+//       ]
+//     }
+//   }
+// }
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DependencyTrackComponent {
+    pub uuid: String,
+    pub group: Option<String>,
+    pub name: String,
+    pub version: Option<String>,
+    pub purl: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyTrackVulnerability {
+    pub uuid: String,
+    pub vuln_id: String,
+    pub source: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub severity: Severity,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DependencyTrackProject {
+    pub uuid: String,
+    pub name: String,
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyTrackSubject {
+    pub component: DependencyTrackComponent,
+    pub vulnerability: DependencyTrackVulnerability,
+    #[serde(default)]
+    pub affected_projects: Vec<DependencyTrackProject>,
+}
+
+#[derive(Display, Debug, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum DependencyTrackGroup {
+    NewVulnerability,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DependencyTrackNotification {
+    pub level: String,
+    pub scope: String,
+    pub group: DependencyTrackGroup,
+    pub timestamp: String,
+    pub title: String,
+    pub subject: DependencyTrackSubject,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DependencyTrackBody {
+    pub notification: DependencyTrackNotification,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum AlertBody {
+    Trivy(TrivyReport),
+    DependencyTrack(Box<DependencyTrackBody>),
+}
@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use warp::{filters::BoxedFilter, hyper::StatusCode, Filter, Rejection, Reply};
+
+use crate::{
+    alert::vuln_report::{
+        message::{AlertBody, Severity},
+        transform::{
+            alert_body_to_findings, severity_to_feishu_template_color,
+            severity_to_notification_severity, Finding,
+        },
+    },
+    common::{check_api_key, log_json, AlertKeyMap, Response},
+    error::NotifyFailedRequestError,
+    notify::{notify, Notification},
+};
+
+const DEFAULT_SEVERITY_THRESHOLD: Severity = Severity::High;
+const DEFAULT_TOP_LIMIT: usize = 10;
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VulnReportQueryParams {
+    pub severity: Option<Severity>,
+    pub limit: Option<usize>,
+}
+
+fn severity_counts(findings: &[Finding]) -> String {
+    Severity::iter()
+        .rev()
+        .map(|severity| {
+            let count = findings.iter().filter(|f| f.severity == severity).count();
+            format!("{}: **{}**", severity, count)
+        })
+        .collect::<Vec<String>>()
+        .join(" | ")
+}
+
+fn top_findings(findings: &[&Finding], limit: usize) -> String {
+    findings
+        .iter()
+        .take(limit)
+        .map(|f| {
+            let fixed = match f.fixed_version {
+                Some(ref fixed_version) if !fixed_version.is_empty() => {
+                    format!(" → {}", fixed_version)
+                }
+                _ => "".to_string(),
+            };
+            let package = match f.installed_version {
+                Some(ref installed_version) if !installed_version.is_empty() => {
+                    format!("{} {}", f.package, installed_version)
+                }
+                _ => f.package.clone(),
+            };
+            format!(
+                "- [{}]({}) **{}** {}{}: {}",
+                f.id,
+                f.url,
+                f.severity,
+                package,
+                fixed,
+                f.title.as_deref().unwrap_or("-"),
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub async fn handle_request(
+    api_keys: Vec<AlertKeyMap>,
+    query: VulnReportQueryParams,
+    body: AlertBody,
+) -> Result<impl Reply, Rejection> {
+    let threshold = query.severity.unwrap_or(DEFAULT_SEVERITY_THRESHOLD);
+    let limit = query.limit.unwrap_or(DEFAULT_TOP_LIMIT);
+    let findings = alert_body_to_findings(&body);
+    let reported = findings
+        .iter()
+        .filter(|f| f.severity >= threshold)
+        .collect::<Vec<&Finding>>();
+
+    if reported.is_empty() {
+        info!(
+            "No vulnerability at or above {} severity, skip notifying",
+            threshold
+        );
+        return Ok(warp::reply::json(
+            &(Response {
+                code: StatusCode::OK.as_u16(),
+                message: "success".to_string(),
+                data: vec![],
+            }),
+        ));
+    }
+
+    let template = severity_to_feishu_template_color(&reported[0].severity);
+    let title = match body {
+        AlertBody::Trivy(ref report) => format!(
+            "Trivy: {} has {} vulnerabilities at or above {}",
+            report.artifact_name,
+            reported.len(),
+            threshold,
+        ),
+        AlertBody::DependencyTrack(ref body) => format!(
+            "Dependency-Track: {} in {}",
+            body.notification.title, reported[0].package,
+        ),
+    };
+    let target = match body {
+        AlertBody::Trivy(ref report) => format!("Artifact: **{}**", report.artifact_name),
+        AlertBody::DependencyTrack(ref body) => format!(
+            "Projects: **{}**",
+            body.notification
+                .subject
+                .affected_projects
+                .iter()
+                .map(|p| match p.version {
+                    Some(ref version) => format!("{} {}", p.name, version),
+                    None => p.name.clone(),
+                })
+                .collect::<Vec<String>>()
+                .join(", "),
+        ),
+    };
+    let message = format!(
+        "{}\n{}\nTop {} of {}:\n{}",
+        target,
+        severity_counts(&findings),
+        reported.len().min(limit),
+        reported.len(),
+        top_findings(&reported, limit),
+    );
+    let notification = Notification {
+        title,
+        url: Some(reported[0].url.clone()),
+        content: message,
+        template: Some(template),
+        severity: Some(severity_to_notification_severity(&reported[0].severity)),
+        labels: [("package".to_string(), reported[0].package.clone())]
+            .into_iter()
+            .chain(match body {
+                AlertBody::Trivy(ref report) => {
+                    Some(("artifact".to_string(), report.artifact_name.clone()))
+                }
+                AlertBody::DependencyTrack(_) => None,
+            })
+            .collect(),
+        raw: serde_json::to_value(&body).ok(),
+        ..Default::default()
+    };
+
+    let results = notify(api_keys, &notification).await.map_err(|err| {
+        warp::reject::custom(NotifyFailedRequestError {
+            message: err.to_string(),
+        })
+    })?;
+
+    Ok(warp::reply::json(
+        &(Response {
+            code: StatusCode::OK.as_u16(),
+            message: "success".to_string(),
+            data: results,
+        }),
+    ))
+}
+
+// POST /api/v1/vuln_report/alerts?apiKey=<api-key>,<api-key>&severity=HIGH&limit=10
+// apiKey format: "feishu_<API_KEY>,dingtalk_<ACCESS_TOKEN>[:<SECRET>]"
+// severity: only post when a vulnerability is at or above it, defaults to HIGH
+pub fn alert() -> BoxedFilter<(impl Reply,)> {
+    warp::post()
+        .and(warp::path!("api" / "v1" / "vuln_report" / "alerts"))
+        .and(warp::body::content_length_limit(1024 * 1024 * 10))
+        .and(check_api_key())
+        .and(warp::query::<VulnReportQueryParams>())
+        .and(log_json())
+        .and_then(handle_request)
+        .boxed()
+}
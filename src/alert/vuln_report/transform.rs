@@ -0,0 +1,91 @@
+use crate::{
+    alert::vuln_report::message::{AlertBody, Severity},
+    notify::{feishu::card::TemplateColor, NotificationSeverity},
+};
+
+#[derive(Debug)]
+pub struct Finding {
+    pub id: String,
+    pub severity: Severity,
+    pub package: String,
+    pub installed_version: Option<String>,
+    pub fixed_version: Option<String>,
+    pub title: Option<String>,
+    pub url: String,
+}
+
+pub fn severity_to_feishu_template_color(severity: &Severity) -> TemplateColor {
+    match severity {
+        Severity::Critical => TemplateColor::Carmine,
+        Severity::High => TemplateColor::Red,
+        Severity::Medium => TemplateColor::Orange,
+        Severity::Low => TemplateColor::Yellow,
+        Severity::Info => TemplateColor::Blue,
+        Severity::Unknown => TemplateColor::Grey,
+    }
+}
+
+fn vulnerability_url(id: &str) -> String {
+    if id.starts_with("GHSA-") {
+        format!("https://github.com/advisories/{}", id)
+    } else {
+        format!("https://nvd.nist.gov/vuln/detail/{}", id)
+    }
+}
+
+// Flatten the report into findings, most severe first.
+pub fn alert_body_to_findings(body: &AlertBody) -> Vec<Finding> {
+    let mut findings = match body {
+        AlertBody::Trivy(ref report) => report
+            .results
+            .iter()
+            .flatten()
+            .flat_map(|result| result.vulnerabilities.iter().flatten())
+            .map(|v| Finding {
+                id: v.vulnerability_id.clone(),
+                severity: v.severity,
+                package: v.pkg_name.clone(),
+                installed_version: v.installed_version.clone(),
+                fixed_version: v.fixed_version.clone(),
+                title: v.title.clone(),
+                url: v
+                    .primary_url
+                    .clone()
+                    .unwrap_or_else(|| vulnerability_url(&v.vulnerability_id)),
+            })
+            .collect::<Vec<Finding>>(),
+        AlertBody::DependencyTrack(ref body) => {
+            let subject = &body.notification.subject;
+            let package = match subject.component.group {
+                Some(ref group) if !group.is_empty() => {
+                    format!("{}/{}", group, subject.component.name)
+                }
+                _ => subject.component.name.clone(),
+            };
+            vec![Finding {
+                id: subject.vulnerability.vuln_id.clone(),
+                severity: subject.vulnerability.severity,
+                package,
+                installed_version: subject.component.version.clone(),
+                fixed_version: None,
+                title: subject
+                    .vulnerability
+                    .title
+                    .clone()
+                    .or_else(|| subject.vulnerability.description.clone()),
+                url: vulnerability_url(&subject.vulnerability.vuln_id),
+            }]
+        }
+    };
+    findings.sort_by(|a, b| b.severity.cmp(&a.severity));
+    findings
+}
+
+pub fn severity_to_notification_severity(severity: &Severity) -> NotificationSeverity {
+    match severity {
+        Severity::Critical => NotificationSeverity::Critical,
+        Severity::High => NotificationSeverity::Error,
+        Severity::Medium => NotificationSeverity::Warning,
+        Severity::Low | Severity::Info | Severity::Unknown => NotificationSeverity::Info,
+    }
+}
@@ -0,0 +1,143 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::notify::{feishu::card::CardButtonType, NotificationSeverity, NotificationStatus};
+
+const fn default_true() -> bool {
+    true
+}
+
+// {
+//   "title": "Nightly backup finished",
+//   "content": "**db-01** backed up in 42 minutes",
+//   "color": "green",
+//   "url": "https://jenkins.example.com/job/backup/123/",
+//   "image": "https://grafana.example.com/render/d-solo/backup.png",
+//   "status": "resolved",
+//   "severity": "info",
+//   "labels": { "host": "db-01" },
+//   "fingerprint": "backup-db-01",
+//   "buttons": [
+//     { "text": "Runbook", "url": "https://wiki.example.com/backup", "type": "default" }
+//   ],
+//   "fields": [
+//     { "name": "Size", "value": "12 GiB", "short": true }
+//   ]
+// }
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageField {
+    pub name: String,
+    pub value: String,
+    #[serde(default = "default_true")]
+    pub short: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageButton {
+    pub text: String,
+    pub url: String,
+    pub r#type: Option<CardButtonType>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageBody {
+    pub title: String,
+    pub content: String,
+    pub color: Option<String>,
+    pub url: Option<String>,
+    pub image: Option<String>,
+    #[serde(default)]
+    pub status: NotificationStatus,
+    pub severity: Option<NotificationSeverity>,
+    #[serde(default)]
+    pub labels: BTreeMap<String, String>,
+    pub fingerprint: Option<String>,
+    #[serde(default)]
+    pub buttons: Vec<MessageButton>,
+    #[serde(default)]
+    pub fields: Vec<MessageField>,
+}
+
+// Form bodies can not nest, so repeated `fields=<name>:<value>`, `labels=<name>:<value>` and
+// `buttons=<text>|<url>` pairs stand in for the JSON arrays and objects:
+// title=Backup&content=done&color=green&fields=Size:12%20GiB&buttons=Runbook|https://...
+impl TryFrom<Vec<(String, String)>> for MessageBody {
+    type Error = String;
+
+    fn try_from(pairs: Vec<(String, String)>) -> Result<Self, Self::Error> {
+        let mut title = None;
+        let mut content = None;
+        let mut color = None;
+        let mut url = None;
+        let mut image = None;
+        let mut status = NotificationStatus::default();
+        let mut severity = None;
+        let mut labels = BTreeMap::new();
+        let mut fingerprint = None;
+        let mut buttons = Vec::new();
+        let mut fields = Vec::new();
+
+        for (key, value) in pairs {
+            match key.as_str() {
+                "title" => title = Some(value),
+                "content" => content = Some(value),
+                "color" => color = Some(value),
+                "url" => url = Some(value),
+                "image" => image = Some(value),
+                "status" => {
+                    status = NotificationStatus::from_str(&value)
+                        .map_err(|_| format!("unknown status `{}`", value))?
+                }
+                "severity" => {
+                    severity = Some(
+                        NotificationSeverity::from_str(&value)
+                            .map_err(|_| format!("unknown severity `{}`", value))?,
+                    )
+                }
+                "fingerprint" => fingerprint = Some(value),
+                "labels" | "labels[]" => match value.split_once(':') {
+                    Some((name, value)) => {
+                        labels.insert(name.trim().to_string(), value.trim().to_string());
+                    }
+                    None => {
+                        return Err(format!("invalid label `{}`, expect <name>:<value>", value))
+                    }
+                },
+                "buttons" | "buttons[]" => match value.split_once('|') {
+                    Some((text, url)) => buttons.push(MessageButton {
+                        text: text.to_string(),
+                        url: url.to_string(),
+                        r#type: None,
+                    }),
+                    None => return Err(format!("invalid button `{}`, expect <text>|<url>", value)),
+                },
+                "fields" | "fields[]" => match value.split_once(':') {
+                    Some((name, value)) => fields.push(MessageField {
+                        name: name.trim().to_string(),
+                        value: value.trim().to_string(),
+                        short: true,
+                    }),
+                    None => {
+                        return Err(format!("invalid field `{}`, expect <name>:<value>", value))
+                    }
+                },
+                _ => {}
+            }
+        }
+
+        Ok(MessageBody {
+            title: title.ok_or_else(|| "missing field `title`".to_string())?,
+            content: content.ok_or_else(|| "missing field `content`".to_string())?,
+            color,
+            url,
+            image,
+            status,
+            severity,
+            labels,
+            fingerprint,
+            buttons,
+            fields,
+        })
+    }
+}
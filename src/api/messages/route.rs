@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+use warp::{filters::BoxedFilter, hyper::StatusCode, Filter, Rejection, Reply};
+
+use crate::{
+    api::messages::message::MessageBody,
+    common::{check_api_key, AlertKeyMap, Response},
+    error::{FormBodyDeserializeError, NotifyFailedRequestError},
+    notify::{
+        feishu::card::TemplateColor, notify, Notification, NotificationButton, NotificationField,
+    },
+};
+
+// Accept both JSON and `application/x-www-form-urlencoded` bodies.
+fn message_body() -> impl Filter<Extract = (MessageBody,), Error = Rejection> + Copy {
+    warp::header::optional::<String>("content-type")
+        .and(warp::body::bytes())
+        .and_then(
+            |content_type: Option<String>, body: bytes::Bytes| async move {
+                let body = std::str::from_utf8(&body).map_err(|err| {
+                    warp::reject::custom(FormBodyDeserializeError {
+                        message: err.to_string(),
+                    })
+                })?;
+
+                let is_form = content_type
+                    .map(|t| t.starts_with("application/x-www-form-urlencoded"))
+                    .unwrap_or(false);
+                let message = if is_form {
+                    info!("Received request form: {}", body);
+                    serde_urlencoded::from_str::<Vec<(String, String)>>(body)
+                        .map_err(|err| err.to_string())
+                        .and_then(MessageBody::try_from)
+                } else {
+                    info!("Received request json: {}", body);
+                    serde_json::from_str::<MessageBody>(body).map_err(|err| err.to_string())
+                };
+
+                message
+                    .map_err(|message| warp::reject::custom(FormBodyDeserializeError { message }))
+            },
+        )
+}
+
+pub async fn handle_request(
+    api_keys: Vec<AlertKeyMap>,
+    body: MessageBody,
+) -> Result<impl Reply, Rejection> {
+    let template = match body.color {
+        Some(ref color) => Some(TemplateColor::from_str(color).map_err(|_| {
+            warp::reject::custom(FormBodyDeserializeError {
+                message: format!("unknown color `{}`", color),
+            })
+        })?),
+        None => None,
+    };
+
+    let notification = Notification {
+        title: body.title,
+        url: body.url,
+        content: body.content,
+        template,
+        fields: body
+            .fields
+            .into_iter()
+            .map(|field| NotificationField {
+                name: field.name,
+                value: field.value,
+                short: field.short,
+            })
+            .collect(),
+        buttons: body
+            .buttons
+            .into_iter()
+            .map(|button| NotificationButton {
+                text: button.text,
+                url: button.url,
+                r#type: button.r#type,
+            })
+            .collect(),
+        image: body.image,
+        status: body.status,
+        severity: body.severity,
+        labels: body.labels,
+        fingerprint: body.fingerprint,
+        // A note and the raw payload are only set by alert sources.
+        ..Default::default()
+    };
+
+    let results = notify(api_keys, &notification).await.map_err(|err| {
+        warp::reject::custom(NotifyFailedRequestError {
+            message: err.to_string(),
+        })
+    })?;
+
+    Ok(warp::reply::json(
+        &(Response {
+            code: StatusCode::OK.as_u16(),
+            message: "success".to_string(),
+            data: results,
+        }),
+    ))
+}
+
+// POST /api/v1/messages?apiKey=<api-key>,<api-key>
+// apiKey format: "feishu_<API_KEY>,dingtalk_<ACCESS_TOKEN>[:<SECRET>]"
+pub fn send() -> BoxedFilter<(impl Reply,)> {
+    warp::post()
+        .and(warp::path!("api" / "v1" / "messages"))
+        .and(warp::body::content_length_limit(1024 * 1024))
+        .and(check_api_key())
+        .and(message_body())
+        .and_then(handle_request)
+        .boxed()
+}
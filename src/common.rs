@@ -1,19 +1,62 @@
-use std::str::FromStr;
 use std::string::ToString;
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
-use strum::{Display, EnumString};
+use strum::{Display, EnumIter, IntoEnumIterator};
 use warp::{Filter, Rejection};
 
 use crate::{
     error::{ConversionError, FormBodyDeserializeError},
-    notify::feishu::api_define::NotifyResponse as FeishuNotifyResponse,
+    notify::{
+        alicloud_sms::api_define::NotifyResponse as AlicloudSmsNotifyResponse,
+        alicloud_vms::api_define::NotifyResponse as AlicloudVmsNotifyResponse,
+        dingtalk::api_define::NotifyResponse as DingtalkNotifyResponse,
+        discord::api_define::NotifyResponse as DiscordNotifyResponse,
+        email::api_define::NotifyResponse as EmailNotifyResponse,
+        feishu::api_define::NotifyResponse as FeishuNotifyResponse,
+        google_chat::api_define::NotifyResponse as GoogleChatNotifyResponse,
+        matrix::api_define::NotifyResponse as MatrixNotifyResponse,
+        mattermost::api_define::NotifyResponse as MattermostNotifyResponse,
+        msteams::api_define::NotifyResponse as MsteamsNotifyResponse,
+        opsgenie::api_define::NotifyResponse as OpsgenieNotifyResponse,
+        pagerduty::api_define::NotifyResponse as PagerdutyNotifyResponse,
+        push::{
+            bark::api_define::NotifyResponse as BarkNotifyResponse,
+            gotify::api_define::NotifyResponse as GotifyNotifyResponse,
+            ntfy::api_define::NotifyResponse as NtfyNotifyResponse,
+            pushover::api_define::NotifyResponse as PushoverNotifyResponse,
+        },
+        rocketchat::api_define::NotifyResponse as RocketchatNotifyResponse,
+        slack::api_define::NotifyResponse as SlackNotifyResponse,
+        telegram::api_define::NotifyResponse as TelegramNotifyResponse,
+        webhook::api_define::NotifyResponse as WebhookNotifyResponse,
+        wecom::api_define::NotifyResponse as WecomNotifyResponse,
+    },
 };
 
-#[derive(Display, Debug, PartialEq, Eq, EnumString)]
+#[derive(Display, Debug, Clone, PartialEq, Eq, EnumIter)]
 #[strum(serialize_all = "snake_case")]
 pub enum AlertDestinations {
     Feishu,
+    Dingtalk,
+    Wecom,
+    Slack,
+    Msteams,
+    Discord,
+    Telegram,
+    Email,
+    Webhook,
+    Mattermost,
+    Rocketchat,
+    Matrix,
+    GoogleChat,
+    Pagerduty,
+    Opsgenie,
+    Ntfy,
+    Gotify,
+    Pushover,
+    Bark,
+    AlicloudSms,
+    AlicloudVms,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -22,7 +65,7 @@ pub struct QueryParams {
     pub api_key: String,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct AlertKeyMap {
     pub destination: AlertDestinations,
     pub key: String,
@@ -39,6 +82,26 @@ pub enum AlertStatus {
 #[serde(untagged)]
 pub enum NotifyResponseEnum {
     Feishu(FeishuNotifyResponse),
+    Dingtalk(DingtalkNotifyResponse),
+    Wecom(WecomNotifyResponse),
+    Slack(SlackNotifyResponse),
+    Msteams(MsteamsNotifyResponse),
+    Discord(DiscordNotifyResponse),
+    Telegram(TelegramNotifyResponse),
+    Email(EmailNotifyResponse),
+    Webhook(WebhookNotifyResponse),
+    Mattermost(MattermostNotifyResponse),
+    Rocketchat(RocketchatNotifyResponse),
+    Matrix(MatrixNotifyResponse),
+    GoogleChat(GoogleChatNotifyResponse),
+    Pagerduty(PagerdutyNotifyResponse),
+    Opsgenie(OpsgenieNotifyResponse),
+    Ntfy(NtfyNotifyResponse),
+    Gotify(GotifyNotifyResponse),
+    Pushover(PushoverNotifyResponse),
+    Bark(BarkNotifyResponse),
+    AlicloudSms(AlicloudSmsNotifyResponse),
+    AlicloudVms(AlicloudVmsNotifyResponse),
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -48,31 +111,28 @@ pub struct Response {
     pub data: Vec<NotifyResponseEnum>,
 }
 
-pub fn check_api_key() -> impl Filter<Extract = (Vec<AlertKeyMap>,), Error = Rejection> + Copy {
-    warp::query::<QueryParams>().and_then(|query: QueryParams| async move {
-        let api_key = query.api_key;
-
-        api_key
-            .split(',')
-            .map(|s| {
-                if !s.contains('_') {
-                    return Err(warp::reject::custom(ConversionError));
-                }
-
-                let mut parts = s.split('_');
-                let destination = parts.next().unwrap();
-                let key = parts.next().unwrap();
-
-                if let Ok(destination) = AlertDestinations::from_str(destination) {
-                    Ok(AlertKeyMap {
+// Parse an api key list such as "feishu_<API_KEY>,dingtalk_<TOKEN>:<SECRET>". The destination
+// is matched by prefix, so both destination names and keys may contain '_'.
+pub fn parse_api_key(api_key: &str) -> Result<Vec<AlertKeyMap>, ConversionError> {
+    api_key
+        .split(',')
+        .map(|s| {
+            AlertDestinations::iter()
+                .find_map(|destination| {
+                    let key = s.strip_prefix(&format!("{}_", destination))?;
+                    Some(AlertKeyMap {
                         destination,
                         key: key.to_string(),
                     })
-                } else {
-                    Err(warp::reject::custom(ConversionError))
-                }
-            })
-            .collect()
+                })
+                .ok_or(ConversionError)
+        })
+        .collect()
+}
+
+pub fn check_api_key() -> impl Filter<Extract = (Vec<AlertKeyMap>,), Error = Rejection> + Copy {
+    warp::query::<QueryParams>().and_then(|query: QueryParams| async move {
+        parse_api_key(&query.api_key).map_err(warp::reject::custom)
     })
 }
 
@@ -0,0 +1,442 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    env, fs,
+    sync::OnceLock,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    alert::{json_webhook::transform::check_webhook, syslog::rule::SyslogRule},
+    notify::NotificationSeverity,
+};
+
+const CONFIG_PATH_ENV: &str = "NOTIFY_BRIDGE_CONFIG";
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+// {
+//   "webhooks": [
+//     {
+//       "name": "sentry",
+//       "title": "[{{ $.action }}] {{ $.data.issue.title }}",
+//       "body": "$.data.issue.culprit",
+//       "severity": "$.data.issue.level",
+//       "status": "$.action",
+//       "url": "$.data.issue.web_url",
+//       "labels": "$.data.issue.metadata",
+//       "fingerprint": "$.data.issue.id",
+//       "colors": { "resolved": "green", "fatal": "carmine", "error": "red" }
+//     }
+//   ],
+//   "cloudevents": [
+//     { "type_prefix": "dev.knative.", "webhook": "knative" }
+//   ],
+//   "smtp_listener": { "port": 2525, "domain": "bridge" },
+//   "syslog": {
+//     "udp_port": 514,
+//     "tcp_port": 514,
+//     "rules": [
+//       {
+//         "name": "idc-switches",
+//         "api_key": "feishu_<API_KEY>",
+//         "facilities": ["local7"],
+//         "severity": "warning",
+//         "hostname": "^sw-",
+//         "pattern": "LINK-3-UPDOWN|%SEC-",
+//         "throttle": 300
+//       }
+//     ]
+//   },
+//   "heartbeats": [
+//     { "id": "nightly-backup", "period": 86400, "grace": 3600, "api_key": "feishu_<API_KEY>" }
+//   ],
+//   "wecom": { "image_hosts": ["grafana.example.com"] },
+//   "telegram": { "api_base_url": "http://localhost:8081" },
+//   "mattermost": { "hosts": ["mm.example.com"] },
+//   "rocketchat": { "hosts": ["chat.example.com"] },
+//   "email": {
+//     "host": "smtp.example.com",
+//     "security": "starttls",
+//     "username": "alerts@example.com",
+//     "password": "<PASSWORD>",
+//     "from": "Notify Bridge <alerts@example.com>",
+//     "groups": { "management": ["cto@example.com", "vendor@partner.com"] },
+//     "allowed_domains": ["example.com"]
+//   },
+//   "opsgenie": { "api_base_url": "https://api.eu.opsgenie.com" },
+//   "ntfy": { "server_url": "https://ntfy.example.com", "token": "<ACCESS_TOKEN>" },
+//   "gotify": { "server_url": "https://gotify.example.com" },
+//   "bark": { "server_url": "https://bark.example.com" },
+//   "matrix": { "homeserver_url": "https://matrix.example.com", "access_token": "<ACCESS_TOKEN>" },
+//   "alicloud": {
+//     "access_key_id": "<ACCESS_KEY_ID>",
+//     "access_key_secret": "<ACCESS_KEY_SECRET>",
+//     "sms": {
+//       "sign_name": "Notify Bridge",
+//       "template_code": "SMS_123456789",
+//       "template_param": { "title": "{{ $.title }}", "severity": "$.severity" },
+//       "min_severity": "error"
+//     },
+//     "vms": {
+//       "tts_code": "TTS_123456789",
+//       "tts_param": { "title": "{{ $.title }}" },
+//       "play_times": 3,
+//       "min_severity": "critical"
+//     }
+//   },
+//   "outbound_webhooks": [
+//     {
+//       "name": "itsm",
+//       "url": "https://itsm.example.com/api/incidents",
+//       "headers": { "X-Team": "{{ $.labels.team }}" },
+//       "body": { "summary": "{{ $.title }}", "priority": "$.severity", "payload": "$.raw" },
+//       "secret": "<SECRET>"
+//     }
+//   ]
+// }
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct Config {
+    #[serde(default)]
+    pub webhooks: Vec<JsonWebhookConfig>,
+    #[serde(default)]
+    pub cloudevents: Vec<CloudEventRouteConfig>,
+    pub smtp_listener: Option<SmtpListenerConfig>,
+    pub syslog: Option<SyslogConfig>,
+    #[serde(default)]
+    pub heartbeats: Vec<HeartbeatConfig>,
+    pub wecom: Option<WecomConfig>,
+    pub telegram: Option<TelegramConfig>,
+    pub email: Option<EmailConfig>,
+    pub mattermost: Option<MattermostConfig>,
+    pub rocketchat: Option<RocketchatConfig>,
+    pub matrix: Option<MatrixConfig>,
+    pub opsgenie: Option<OpsgenieConfig>,
+    pub ntfy: Option<NtfyConfig>,
+    pub gotify: Option<GotifyConfig>,
+    pub bark: Option<BarkConfig>,
+    pub alicloud: Option<AlicloudConfig>,
+    #[serde(default)]
+    pub outbound_webhooks: Vec<OutboundWebhookConfig>,
+}
+
+// Every mapping is either a JSONPath (`$.a.b[0]`) or a template with `{{ <JSONPath> }}`
+// placeholders.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct JsonWebhookConfig {
+    pub name: String,
+    pub title: String,
+    pub body: Option<String>,
+    pub severity: Option<String>,
+    pub status: Option<String>,
+    pub url: Option<String>,
+    pub labels: Option<String>,
+    // Identifies the alert across its notifications, for threads and incident deduplication.
+    pub fingerprint: Option<String>,
+    // Status or severity value to `TemplateColor` name, status wins when both match.
+    #[serde(default)]
+    pub colors: HashMap<String, String>,
+}
+
+// CloudEvents whose `type` starts with `type_prefix` are rendered by the named webhook mapping,
+// which sees the whole event, e.g. `$.subject` or `$.data.reason`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CloudEventRouteConfig {
+    pub type_prefix: String,
+    pub webhook: String,
+}
+
+const fn default_max_message_size() -> usize {
+    1024 * 1024 * 10
+}
+
+const fn default_idle_timeout() -> u64 {
+    300
+}
+
+const fn default_max_connections() -> usize {
+    100
+}
+
+// Mail to `<anything>+<api-key>@<domain>` is delivered to the destination of the api key, e.g.
+// `ops+feishu_<API_KEY>@bridge`. Any domain is accepted when `domain` is unset. A client silent for
+// `idle_timeout` seconds is disconnected, and clients beyond `max_connections` wait to be accepted.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SmtpListenerConfig {
+    pub port: u16,
+    pub domain: Option<String>,
+    #[serde(default = "default_max_message_size")]
+    pub max_message_size: usize,
+    #[serde(default = "default_idle_timeout")]
+    pub idle_timeout: u64,
+    #[serde(default = "default_max_connections")]
+    pub max_connections: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SyslogConfig {
+    pub udp_port: Option<u16>,
+    pub tcp_port: Option<u16>,
+    #[serde(default)]
+    pub rules: Vec<SyslogRuleConfig>,
+}
+
+// A line is notified to `api_key` when it matches every condition that is set: one of the
+// `facilities`, at least as severe as `severity`, and the `hostname`/`pattern` regexes. Names such
+// as `local7` or `warning` and the regexes are checked when the config is loaded. A rule notifies
+// at most once per `throttle` seconds, 0 to notify every match.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SyslogRuleConfig {
+    pub name: String,
+    pub api_key: String,
+    #[serde(default)]
+    pub facilities: Vec<String>,
+    pub severity: Option<String>,
+    pub hostname: Option<String>,
+    pub pattern: Option<String>,
+    #[serde(default = "default_syslog_throttle")]
+    pub throttle: u64,
+}
+
+const fn default_syslog_throttle() -> u64 {
+    60
+}
+
+// A check is down when no ping arrives within `period` + `grace` seconds, both counted from
+// the last ping, or from the start of the bridge before the first one.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HeartbeatConfig {
+    pub id: String,
+    pub name: Option<String>,
+    pub period: u64,
+    #[serde(default)]
+    pub grace: u64,
+    pub api_key: String,
+}
+
+// The hosts images are downloaded from to be sent to WeCom, `.example.com` for its subdomains.
+// Images are not sent when unset.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct WecomConfig {
+    #[serde(default)]
+    pub image_hosts: Vec<String>,
+}
+
+// Point to a self-hosted Bot API server, or a mock one in tests.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TelegramConfig {
+    pub api_base_url: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailSecurity {
+    // Upgrade with STARTTLS, on port 587 by default.
+    #[default]
+    Starttls,
+    // Implicit TLS, on port 465 by default.
+    Tls,
+    // Plain text, on port 25 by default. Only for a relay on a trusted network.
+    None,
+}
+
+// The SMTP relay to send emails through. `groups` name lists of recipients, so an api key such
+// as `email_management` stands for all of them. Other addresses in an api key must be on one of
+// the `allowed_domains`, none when unset.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EmailConfig {
+    pub host: String,
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub security: EmailSecurity,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub from: String,
+    #[serde(default)]
+    pub groups: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub allowed_domains: Vec<String>,
+}
+
+// `https://api.eu.opsgenie.com` for accounts in the EU, or a mock in tests.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct OpsgenieConfig {
+    pub api_base_url: String,
+}
+
+// A self-hosted ntfy server instead of ntfy.sh, with the access token of a user allowed to publish.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NtfyConfig {
+    pub server_url: String,
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GotifyConfig {
+    pub server_url: String,
+}
+
+// A self-hosted bark-server instead of api.day.app.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BarkConfig {
+    pub server_url: String,
+}
+
+// Mattermost and Rocket.Chat are self-hosted, so their webhook urls are only posted to on these
+// hosts, `.example.com` for its subdomains.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MattermostConfig {
+    pub hosts: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RocketchatConfig {
+    pub hosts: Vec<String>,
+}
+
+// The homeserver and the access token of the user sending to the rooms, such as a bot account.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MatrixConfig {
+    pub homeserver_url: String,
+    pub access_token: String,
+}
+
+fn default_region_id() -> String {
+    "cn-hangzhou".to_string()
+}
+
+fn default_sms_endpoint() -> String {
+    "https://dysmsapi.aliyuncs.com".to_string()
+}
+
+fn default_vms_endpoint() -> String {
+    "https://dyvmsapi.aliyuncs.com".to_string()
+}
+
+fn default_vms_min_severity() -> NotificationSeverity {
+    NotificationSeverity::Critical
+}
+
+// The AccessKey of a RAM user allowed to call `dysms:SendSms` and `dyvms:SingleCallByTts`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AlicloudConfig {
+    pub access_key_id: String,
+    pub access_key_secret: String,
+    #[serde(default = "default_region_id")]
+    pub region_id: String,
+    pub sms: Option<AlicloudSmsConfig>,
+    pub vms: Option<AlicloudVmsConfig>,
+}
+
+// `template_param` values are templates over the normalized alert of `outbound_webhooks`, one per
+// variable of the approved template. Alerts less severe than `min_severity` are not sent.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AlicloudSmsConfig {
+    #[serde(default = "default_sms_endpoint")]
+    pub endpoint: String,
+    pub sign_name: String,
+    pub template_code: String,
+    #[serde(default)]
+    pub template_param: BTreeMap<String, String>,
+    pub min_severity: Option<NotificationSeverity>,
+}
+
+// Text-to-speech calls, `called_show_number` is one of the purchased numbers, or the public pool
+// when unset. Resolved alerts never place a call, nor alerts less severe than `min_severity`,
+// critical by default.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct AlicloudVmsConfig {
+    #[serde(default = "default_vms_endpoint")]
+    pub endpoint: String,
+    pub called_show_number: Option<String>,
+    pub tts_code: String,
+    #[serde(default)]
+    pub tts_param: BTreeMap<String, String>,
+    pub play_times: Option<u8>,
+    #[serde(default = "default_vms_min_severity")]
+    pub min_severity: NotificationSeverity,
+}
+
+fn default_method() -> String {
+    "POST".to_string()
+}
+
+fn default_signature_header() -> String {
+    "X-Signature-256".to_string()
+}
+
+// Send the normalized alert to `url`, or the `body` template rendered with it. `url`, `headers`
+// values and string leaves of `body` are templates as in `webhooks`, where a leaf that is a single
+// JSONPath keeps the type of the selected value, and the placeholders of `url` are percent-encoded.
+// With a `secret`, the body is signed as `sha256=<hex HMAC-SHA256>` in `signature_header`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct OutboundWebhookConfig {
+    pub name: String,
+    pub url: String,
+    #[serde(default = "default_method")]
+    pub method: String,
+    #[serde(default)]
+    pub headers: HashMap<String, String>,
+    pub body: Option<Value>,
+    pub secret: Option<String>,
+    #[serde(default = "default_signature_header")]
+    pub signature_header: String,
+}
+
+impl Config {
+    pub fn webhook(&self, name: &str) -> Option<&JsonWebhookConfig> {
+        self.webhooks.iter().find(|webhook| webhook.name == name)
+    }
+
+    pub fn outbound_webhook(&self, name: &str) -> Option<&OutboundWebhookConfig> {
+        self.outbound_webhooks
+            .iter()
+            .find(|webhook| webhook.name == name)
+    }
+
+    pub fn heartbeat(&self, id: &str) -> Option<&HeartbeatConfig> {
+        self.heartbeats.iter().find(|heartbeat| heartbeat.id == id)
+    }
+
+    // The longest matching `type_prefix` wins.
+    pub fn cloudevent_route(&self, r#type: &str) -> Option<&CloudEventRouteConfig> {
+        self.cloudevents
+            .iter()
+            .filter(|route| r#type.starts_with(&route.type_prefix))
+            .max_by_key(|route| route.type_prefix.len())
+    }
+}
+
+// Load the JSON config file named by `NOTIFY_BRIDGE_CONFIG`, an empty config is used if unset.
+pub fn init() -> Result<(), Box<dyn std::error::Error>> {
+    let config = match env::var(CONFIG_PATH_ENV) {
+        Ok(path) => {
+            info!("Load config from {}", path);
+            serde_json::from_str(&fs::read_to_string(path)?)?
+        }
+        Err(_) => Config::default(),
+    };
+    check(&config)?;
+    CONFIG
+        .set(config)
+        .map_err(|_| "config is already initialized".into())
+}
+
+// Templates are otherwise only parsed when a request hits them.
+fn check(config: &Config) -> Result<(), Box<dyn std::error::Error>> {
+    for webhook in &config.webhooks {
+        check_webhook(webhook)
+            .map_err(|message| format!("invalid webhook {}: {}", webhook.name, message))?;
+    }
+    if let Some(ref syslog) = config.syslog {
+        for rule in &syslog.rules {
+            SyslogRule::compile(rule)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn config() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
@@ -18,10 +18,10 @@ pub struct ConversionError;
 impl Reject for ConversionError {}
 
 #[derive(Debug)]
-pub struct FeishuFailedRequestError {
+pub struct NotifyFailedRequestError {
     pub message: String,
 }
-impl Reject for FeishuFailedRequestError {}
+impl Reject for NotifyFailedRequestError {}
 
 #[derive(Debug)]
 pub struct FormBodyDeserializeError {
@@ -29,6 +29,12 @@ pub struct FormBodyDeserializeError {
 }
 impl Reject for FormBodyDeserializeError {}
 
+#[derive(Debug)]
+pub struct InvalidConfigError {
+    pub message: String,
+}
+impl Reject for InvalidConfigError {}
+
 pub async fn handle_rejection(err: warp::Rejection) -> Result<impl warp::Reply, warp::Rejection> {
     let code;
     let message;
@@ -56,9 +62,19 @@ pub async fn handle_rejection(err: warp::Rejection) -> Result<impl warp::Reply,
     } else if let Some(e) = err.find::<FormBodyDeserializeError>() {
         code = StatusCode::BAD_REQUEST;
         message = &e.message;
-    } else if let Some(e) = err.find::<FeishuFailedRequestError>() {
+    } else if let Some(e) = err.find::<NotifyFailedRequestError>() {
         code = StatusCode::BAD_REQUEST;
         message = &e.message;
+    } else if let Some(e) = err.find::<InvalidConfigError>() {
+        code = StatusCode::INTERNAL_SERVER_ERROR;
+        error!("Invalid config: {}", e.message);
+        message = &e.message;
+    } else if err.find::<warp::reject::PayloadTooLarge>().is_some() {
+        code = StatusCode::PAYLOAD_TOO_LARGE;
+        message = "Payload Too Large";
+    } else if err.find::<warp::reject::LengthRequired>().is_some() {
+        code = StatusCode::LENGTH_REQUIRED;
+        message = "Length Required";
     } else if err.find::<warp::reject::MethodNotAllowed>().is_some() {
         code = StatusCode::METHOD_NOT_ALLOWED;
         message = "Method Not Allowed";
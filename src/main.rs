@@ -1,29 +1,58 @@
 #![feature(box_into_inner)]
+#![feature(once_cell)]
 extern crate pretty_env_logger;
 #[macro_use]
 extern crate log;
 
 mod alert;
+mod api;
 mod common;
+mod config;
 mod error;
 mod notify;
+mod template;
 
 use warp::Filter;
 
 use crate::{
     alert::alicloud_monitor::route::alert as alicloud_monitor_alert,
-    alert::grafana::route::alert as grafana_alert, error::handle_rejection,
+    alert::cloudevents::route::alert as cloudevents_alert,
+    alert::grafana::route::alert as grafana_alert, alert::graylog::route::alert as graylog_alert,
+    alert::heartbeat::route::alert as heartbeat_alert,
+    alert::json_webhook::route::alert as json_webhook_alert,
+    alert::nagios::route::alert as nagios_alert, alert::netdata::route::alert as netdata_alert,
+    alert::vuln_report::route::alert as vuln_report_alert,
+    api::messages::route::send as send_message, error::handle_rejection,
 };
 
 #[tokio::main]
 async fn main() {
     pretty_env_logger::init();
+    config::init().expect("Failed to load config");
+
+    if let Some(ref smtp_listener) = config::config().smtp_listener {
+        tokio::spawn(alert::smtp::server::serve(smtp_listener));
+    }
+    if let Some(ref syslog) = config::config().syslog {
+        tokio::spawn(alert::syslog::server::serve(syslog));
+    }
+    if !config::config().heartbeats.is_empty() {
+        tokio::spawn(alert::heartbeat::monitor::monitor());
+    }
 
     let health = warp::path!("health").map(|| "OK").boxed();
 
     let routes = health
         .or(grafana_alert())
         .or(alicloud_monitor_alert())
+        .or(graylog_alert())
+        .or(nagios_alert())
+        .or(netdata_alert())
+        .or(vuln_report_alert())
+        .or(json_webhook_alert())
+        .or(cloudevents_alert())
+        .or(heartbeat_alert())
+        .or(send_message())
         .recover(handle_rejection);
 
     warp::serve(routes).run(([0, 0, 0, 0], 3030)).await;
@@ -1 +1,532 @@
+pub(crate) mod alicloud_rpc;
+pub(crate) mod alicloud_sms;
+pub(crate) mod alicloud_vms;
+pub(crate) mod dingtalk;
+pub(crate) mod discord;
+pub(crate) mod email;
 pub(crate) mod feishu;
+pub(crate) mod google_chat;
+pub(crate) mod matrix;
+pub(crate) mod mattermost;
+pub(crate) mod msteams;
+pub(crate) mod opsgenie;
+pub(crate) mod pagerduty;
+pub(crate) mod push;
+pub(crate) mod rocketchat;
+pub(crate) mod slack;
+pub(crate) mod telegram;
+pub(crate) mod webhook;
+pub(crate) mod wecom;
+
+use std::{
+    collections::BTreeMap,
+    sync::LazyLock,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use regex::Regex;
+use reqwest::Url;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum::{Display, EnumString};
+
+use crate::{
+    common::{AlertDestinations, AlertKeyMap, NotifyResponseEnum},
+    notify::feishu::card::{CardButtonType, TemplateColor},
+};
+
+#[derive(Debug, Clone)]
+pub struct NotificationField {
+    pub name: String,
+    pub value: String,
+    pub short: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct NotificationButton {
+    pub text: String,
+    pub url: String,
+    pub r#type: Option<CardButtonType>,
+}
+
+#[derive(
+    Display, Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum NotificationStatus {
+    #[default]
+    Firing,
+    Resolved,
+}
+
+// Ordered from the most severe, so "at least as severe as" compares with `<=`.
+#[derive(
+    Display, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize, EnumString,
+)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum NotificationSeverity {
+    Critical,
+    Error,
+    Warning,
+    Info,
+}
+
+// What an alert source hands to every destination. `content` and `note` are lark_md, which each
+// destination converts to its own markup. `image` is a URL, sent by the destinations that can show
+// one. `status`, `severity`, `labels` and the `raw` payload are not shown, but let incident
+// management and outbound webhooks act on the alert. `fingerprint` identifies the alert across its
+// firing and resolved notifications, when the source has such an identity. `informational`
+// notifications, such as an acknowledgement, tell about the alert without changing its status, so
+// incident management leaves them out.
+#[derive(Debug, Clone, Default)]
+pub struct Notification {
+    pub title: String,
+    pub url: Option<String>,
+    pub content: String,
+    pub template: Option<TemplateColor>,
+    pub fields: Vec<NotificationField>,
+    pub buttons: Vec<NotificationButton>,
+    pub note: Option<String>,
+    pub image: Option<String>,
+    pub status: NotificationStatus,
+    pub severity: Option<NotificationSeverity>,
+    pub labels: BTreeMap<String, String>,
+    pub fingerprint: Option<String>,
+    pub informational: bool,
+    pub raw: Option<Value>,
+}
+
+// Feishu template colors as hex, for destinations that take a color code.
+pub fn template_color_hex(template: &TemplateColor) -> &'static str {
+    match template {
+        TemplateColor::Green | TemplateColor::Success | TemplateColor::Completed => "#2EA121",
+        TemplateColor::Orange | TemplateColor::Warning | TemplateColor::Notify => "#FF8800",
+        TemplateColor::Red | TemplateColor::Error | TemplateColor::Failed => "#F54A45",
+        TemplateColor::Grey | TemplateColor::Disabled | TemplateColor::Invalid => "#8F959E",
+        TemplateColor::Blue => "#3370FF",
+        TemplateColor::Wathet => "#49B4EE",
+        TemplateColor::Turquoise => "#2BC7A9",
+        TemplateColor::Yellow => "#DEB000",
+        TemplateColor::Carmine => "#C71E69",
+        TemplateColor::Violet => "#D136D1",
+        TemplateColor::Purple => "#7F3BF5",
+        TemplateColor::Indigo => "#4E53E8",
+    }
+}
+
+// Drop the lark_md markup for destinations that only show plain text, links keep their text.
+pub fn lark_md_to_plain_text(text: &str) -> String {
+    static LINK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[([^\]]*)\]\([^)]*\)").unwrap());
+    LINK.replace_all(text, "$1")
+        .replace("**", "")
+        .replace("~~", "")
+}
+
+// lark_md => Markdown: `<font color=...>text</font>` => `text`, `<at id=all></at>` => `@all` and
+// `<at id=...>name</at>` => `@name`. Bold, strike and links are Markdown already.
+pub fn lark_md_to_markdown(text: &str) -> String {
+    static FONT: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"(?s)<font[^>]*>(.*?)</font>").unwrap());
+    static AT: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r#"<at\s+[a-z_]+=["']?([^"'\s>]*)["']?\s*>([^<]*)</at>"#).unwrap()
+    });
+
+    let text = FONT.replace_all(text, "$1");
+    AT.replace_all(&text, |captures: &regex::Captures| match &captures[2] {
+        "" => format!("@{}", &captures[1]),
+        name => format!("@{}", name),
+    })
+    .to_string()
+}
+
+// Cut to `max_length` characters, the unit of most destination limits.
+pub fn truncate(text: &str, max_length: usize) -> String {
+    match text.char_indices().nth(max_length) {
+        Some((index, _)) => text[..index].to_string(),
+        None => text.to_string(),
+    }
+}
+
+// Cut the lark_md rather than its `markup`, so no tag or entity is cut in half, and cut more until
+// the converted text fits.
+pub fn truncate_markup(text: &str, max_length: usize, markup: fn(&str) -> String) -> String {
+    let mut length = max_length;
+    loop {
+        let converted = markup(&truncate(text, length));
+        match converted.chars().count().saturating_sub(max_length) {
+            0 => return converted,
+            excess => length -= excess.min(length),
+        }
+    }
+}
+
+// The current time in RFC 3339, such as `2022-08-01T08:00:00Z`.
+pub fn utc_timestamp() -> String {
+    rfc3339(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default(),
+    )
+}
+
+fn rfc3339(seconds: u64) -> String {
+    let (days, time) = ((seconds / 86400) as i64, seconds % 86400);
+
+    // Days since 1970-01-01 to a civil date, http://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    )
+}
+
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// lark_md => HTML: `**bold**` => `<b>bold</b>`, `~~strike~~` => `<s>strike</s>`,
+// `[text](url)` => `<a href="url">text</a>`. Everything else is escaped, line breaks are kept.
+pub fn lark_md_to_html(text: &str) -> String {
+    static LINK: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"\[([^\]]*)\]\(([^)]*)\)").unwrap());
+    static BOLD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\*\*(.+?)\*\*").unwrap());
+    static STRIKE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"~~(.+?)~~").unwrap());
+
+    let text = escape_html(text);
+    let text = LINK.replace_all(&text, "<a href=\"$2\">$1</a>");
+    let text = BOLD.replace_all(&text, "<b>$1</b>");
+    STRIKE.replace_all(&text, "<s>$1</s>").to_string()
+}
+
+// Only reach the hosts a destination expects, so an api key or an alert cannot point the bridge at
+// an internal service. `.example.com` matches the subdomains of example.com.
+pub fn check_url_host<S: AsRef<str>>(
+    url: &Url,
+    hosts: &[S],
+) -> Result<(), Box<dyn std::error::Error>> {
+    let host = url.host_str().unwrap_or_default().to_ascii_lowercase();
+    let allowed = hosts.iter().any(|allowed| match allowed.as_ref() {
+        suffix if suffix.starts_with('.') => host.ends_with(suffix),
+        allowed => host == allowed,
+    });
+    match allowed && matches!(url.scheme(), "http" | "https") {
+        true => Ok(()),
+        false => Err(format!("host of `{}` is not allowed", url).into()),
+    }
+}
+
+// Move the `names` query parameters out of a webhook url, for destinations that take per-message
+// overrides such as `https://chat.example.com/hooks/<KEY>?channel=alerts`.
+pub fn take_url_options(
+    url: &str,
+    names: &[&str],
+) -> Result<(Url, BTreeMap<String, String>), Box<dyn std::error::Error>> {
+    let mut url = Url::parse(url)?;
+    let (options, query): (Vec<_>, Vec<_>) = url
+        .query_pairs()
+        .into_owned()
+        .partition(|(name, _)| names.contains(&name.as_str()));
+    match query.is_empty() {
+        true => url.set_query(None),
+        false => {
+            url.query_pairs_mut().clear().extend_pairs(query);
+        }
+    }
+    Ok((
+        url,
+        options
+            .into_iter()
+            .filter(|(_, value)| !value.is_empty())
+            .collect(),
+    ))
+}
+
+// Send the notification to every destination, stopping at the first failed request.
+pub async fn notify(
+    api_keys: Vec<AlertKeyMap>,
+    notification: &Notification,
+) -> Result<Vec<NotifyResponseEnum>, Box<dyn std::error::Error>> {
+    let mut results = Vec::new();
+    for api_key in api_keys {
+        let destination = api_key.destination.to_string();
+        let result = match api_key.destination {
+            AlertDestinations::Feishu => {
+                let (status, result) = feishu::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Feishu(feishu::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::AlicloudSms => {
+                let (status, result) = alicloud_sms::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::AlicloudSms(alicloud_sms::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::AlicloudVms => {
+                let (status, result) = alicloud_vms::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::AlicloudVms(alicloud_vms::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Bark => {
+                let (status, result) = push::bark::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Bark(push::bark::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Dingtalk => {
+                let (status, result) = dingtalk::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Dingtalk(dingtalk::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Discord => {
+                let (status, result) = discord::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Discord(discord::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Email => {
+                let (status, result) = email::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Email(email::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::GoogleChat => {
+                let (status, result) = google_chat::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::GoogleChat(google_chat::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Gotify => {
+                let (status, result) = push::gotify::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Gotify(push::gotify::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Matrix => {
+                let (status, result) = matrix::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Matrix(matrix::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Mattermost => {
+                let (status, result) = mattermost::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Mattermost(mattermost::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Msteams => {
+                let (status, result) = msteams::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Msteams(msteams::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Ntfy => {
+                let (status, result) = push::ntfy::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Ntfy(push::ntfy::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Opsgenie => {
+                let (status, result) = opsgenie::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Opsgenie(opsgenie::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Pagerduty => {
+                let (status, result) = pagerduty::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Pagerduty(pagerduty::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Pushover => {
+                let (status, result) =
+                    push::pushover::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Pushover(push::pushover::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Rocketchat => {
+                let (status, result) = rocketchat::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Rocketchat(rocketchat::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Slack => {
+                let (status, result) = slack::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Slack(slack::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Telegram => {
+                let (status, result) = telegram::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Telegram(telegram::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Webhook => {
+                let (status, result) = webhook::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Webhook(webhook::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+            AlertDestinations::Wecom => {
+                let (status, result) = wecom::post::post(api_key.key, notification).await?;
+                NotifyResponseEnum::Wecom(wecom::api_define::NotifyResponse {
+                    destination,
+                    status,
+                    result,
+                })
+            }
+        };
+        results.push(result);
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_text_from_lark_md() {
+        assert_eq!(
+            lark_md_to_plain_text("**db-01** is ~~up~~ down, see [runbook](https://wiki/x)"),
+            "db-01 is up down, see runbook"
+        );
+        assert_eq!(lark_md_to_plain_text("a * b < c"), "a * b < c");
+    }
+
+    #[test]
+    fn markdown_from_lark_md() {
+        assert_eq!(
+            lark_md_to_markdown(
+                "<font color='red'>**down**</font> <at id=all></at> <at id=\"ou_1\">Ann</at>"
+            ),
+            "**down** @all @Ann"
+        );
+        assert_eq!(
+            lark_md_to_markdown("a < b [x](https://x)"),
+            "a < b [x](https://x)"
+        );
+    }
+
+    #[test]
+    fn truncate_characters() {
+        assert_eq!(truncate("héllo", 2), "hé");
+        assert_eq!(truncate("héllo", 5), "héllo");
+        assert_eq!(truncate("", 0), "");
+    }
+
+    #[test]
+    fn truncate_before_markup() {
+        assert_eq!(truncate_markup("a & b", 7, lark_md_to_html), "a &amp;");
+        assert_eq!(truncate_markup("**bold**", 6, lark_md_to_html), "**bold");
+        assert_eq!(truncate_markup("&&", 4, lark_md_to_html), "");
+    }
+
+    #[test]
+    fn rfc3339_dates() {
+        assert_eq!(rfc3339(0), "1970-01-01T00:00:00Z");
+        assert_eq!(rfc3339(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(rfc3339(1_659_340_800), "2022-08-01T08:00:00Z");
+        assert_eq!(rfc3339(1_709_251_199), "2024-02-29T23:59:59Z");
+        assert_eq!(rfc3339(4_107_542_400), "2100-03-01T00:00:00Z");
+    }
+
+    #[test]
+    fn url_hosts() {
+        let hosts = ["grafana.example.com", ".example.org"];
+        let allowed = |url: &str| check_url_host(&Url::parse(url).unwrap(), &hosts).is_ok();
+        assert!(allowed("https://grafana.example.com/render/a.png"));
+        assert!(allowed("http://GRAFANA.example.com:3000/a.png"));
+        assert!(allowed("https://img.example.org/a.png"));
+        assert!(!allowed("https://example.org/a.png"));
+        assert!(!allowed("https://grafana.example.com.evil.com/a.png"));
+        assert!(!allowed("https://evilexample.org/a.png"));
+        assert!(!allowed("file://grafana.example.com/etc/passwd"));
+        assert!(!allowed("http://127.0.0.1/a.png"));
+    }
+
+    #[test]
+    fn escape_html_entities() {
+        assert_eq!(
+            escape_html(r#"<a href="x">&amp;</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&amp;amp;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn html_from_lark_md() {
+        assert_eq!(
+            lark_md_to_html("**db-01** & <b> ~~up~~\n[logs](https://x?a=1&b=2)"),
+            "<b>db-01</b> &amp; &lt;b&gt; <s>up</s>\n<a href=\"https://x?a=1&amp;b=2\">logs</a>"
+        );
+        assert_eq!(
+            lark_md_to_html("[x](https://x\" onclick=\"y)"),
+            "<a href=\"https://x&quot; onclick=&quot;y\">x</a>"
+        );
+    }
+}
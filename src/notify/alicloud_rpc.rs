@@ -0,0 +1,133 @@
+use std::{
+    collections::BTreeMap,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use openssl::{hash::MessageDigest, pkey::PKey, sign::Signer};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+
+use crate::{config::AlicloudConfig, notify::utc_timestamp};
+
+// RFC 3986 unreserved characters are kept, everything else is encoded, spaces as `%20`.
+const RPC_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+static NONCES: AtomicU64 = AtomicU64::new(0);
+
+fn percent_encode(text: &str) -> String {
+    utf8_percent_encode(text, RPC_ENCODE_SET).to_string()
+}
+
+fn nonce() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos())
+        .unwrap_or_default();
+    format!("{}-{}", nanos, NONCES.fetch_add(1, Ordering::Relaxed))
+}
+
+// Signature v1: the parameters sorted by name form the canonicalized query, and
+// Signature = base64(HmacSHA1("GET&%2F&" + encode(<canonicalized query>), <secret> + "&")).
+pub fn sign(
+    method: &str,
+    query: &str,
+    access_key_secret: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let string_to_sign = format!(
+        "{}&{}&{}",
+        method,
+        percent_encode("/"),
+        percent_encode(query)
+    );
+    let key = PKey::hmac(format!("{}&", access_key_secret).as_bytes())?;
+    let mut signer = Signer::new(MessageDigest::sha1(), &key)?;
+    let signature = signer.sign_oneshot_to_vec(string_to_sign.as_bytes())?;
+    Ok(openssl::base64::encode_block(&signature))
+}
+
+// Call an RPC style API such as `SendSms` with the common parameters and the signature. Errors
+// such as a throttled request come with a 4xx status and the same JSON body as successes.
+pub async fn call<T: DeserializeOwned>(
+    alicloud: &AlicloudConfig,
+    endpoint: &str,
+    action: &str,
+    version: &str,
+    params: Vec<(&str, String)>,
+) -> Result<T, Box<dyn std::error::Error>> {
+    let mut query = params
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value))
+        .collect::<BTreeMap<String, String>>();
+    query.extend(
+        [
+            ("AccessKeyId", alicloud.access_key_id.clone()),
+            ("Action", action.to_string()),
+            ("Format", "JSON".to_string()),
+            ("RegionId", alicloud.region_id.clone()),
+            ("SignatureMethod", "HMAC-SHA1".to_string()),
+            ("SignatureNonce", nonce()),
+            ("SignatureVersion", "1.0".to_string()),
+            ("Timestamp", utc_timestamp()),
+            ("Version", version.to_string()),
+        ]
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value)),
+    );
+    let query = query
+        .iter()
+        .map(|(name, value)| format!("{}={}", percent_encode(name), percent_encode(value)))
+        .collect::<Vec<String>>()
+        .join("&");
+    let signature = sign("GET", &query, &alicloud.access_key_secret)?;
+
+    let client = Client::new();
+    let response = client
+        .get(&format!(
+            "{}/?{}&Signature={}",
+            endpoint.trim_end_matches('/'),
+            query,
+            percent_encode(&signature)
+        ))
+        .send()
+        .await?;
+
+    let status_code = response.status();
+    if status_code.is_server_error() {
+        return Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!(
+                "Failed to send Alibaba Cloud {} request, status: {}",
+                action, status_code
+            ),
+        )));
+    }
+    Ok(response.json().await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example of the signature documentation.
+    #[test]
+    fn sign_documented_example() {
+        let query = "AccessKeyId=testid&Action=DescribeRegions&Format=XML\
+                     &SignatureMethod=HMAC-SHA1&SignatureNonce=3ee8c1b8-83d3-44af-a94f-4e0ad82fd6cf\
+                     &SignatureVersion=1.0&Timestamp=2016-02-23T12%3A46%3A24Z&Version=2014-05-26";
+        assert_eq!(
+            sign("GET", query, "testsecret").unwrap(),
+            "OLeaidS1JvxuMvnyHOwuJ+uX5qY="
+        );
+    }
+
+    #[test]
+    fn percent_encode_unreserved() {
+        assert_eq!(percent_encode("a-_.~ b*c/é"), "a-_.~%20b%2Ac%2F%C3%A9");
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+pub const SMS_API_VERSION: &str = "2017-05-25";
+
+// {"Code": "OK", "Message": "OK", "RequestId": "F655A8D5-B967-440B-8683-DAD6FF8DE990", "BizId": "900619746936498440^0"}
+// {"Code": "isv.BUSINESS_LIMIT_CONTROL", "Message": "触发分钟级流控Permits:1", "RequestId": "..."}
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct APIResponse {
+    pub code: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub biz_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
@@ -0,0 +1,31 @@
+use std::collections::BTreeMap;
+
+// The parameters of `SendSms`, `TemplateParam` fills the `${name}` variables of the template:
+// PhoneNumbers=13800000000,13900000000
+// SignName=Notify Bridge
+// TemplateCode=SMS_123456789
+// TemplateParam={"title":"[Alerting] High CPU","severity":"critical"}
+#[derive(Debug)]
+pub struct Message {
+    pub phone_numbers: Vec<String>,
+    pub sign_name: String,
+    pub template_code: String,
+    pub template_param: BTreeMap<String, String>,
+}
+
+impl Message {
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("PhoneNumbers", self.phone_numbers.join(",")),
+            ("SignName", self.sign_name.clone()),
+            ("TemplateCode", self.template_code.clone()),
+        ];
+        if !self.template_param.is_empty() {
+            params.push((
+                "TemplateParam",
+                serde_json::to_string(&self.template_param).unwrap(),
+            ));
+        }
+        params
+    }
+}
@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::{
+    common::AlertStatus,
+    config::config,
+    notify::{
+        alicloud_rpc::call,
+        alicloud_sms::{
+            api_define::{APIResponse, SMS_API_VERSION},
+            message::Message,
+        },
+        Notification,
+    },
+    template::{payload, render},
+};
+
+// Render each template over the normalized alert of outbound webhooks, e.g. `{{ $.title }}`.
+pub fn render_params(
+    templates: &BTreeMap<String, String>,
+    notification: &Notification,
+) -> Result<BTreeMap<String, String>, Box<dyn std::error::Error>> {
+    let payload: Value = serde_json::to_value(payload(notification))?;
+    templates
+        .iter()
+        .map(|(name, template)| Ok((name.clone(), render(template, &payload)?)))
+        .collect()
+}
+
+// api_key format: "<PHONE_NUMBER>|<PHONE_NUMBER>". Alerts less severe than `min_severity` are
+// skipped, as well as notifications without a severity when it is set.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let alicloud = config()
+        .alicloud
+        .as_ref()
+        .ok_or("alicloud_sms destination needs the `alicloud` account in the config")?;
+    let sms = alicloud
+        .sms
+        .as_ref()
+        .ok_or("alicloud_sms destination needs the `alicloud.sms` template in the config")?;
+
+    if let Some(min_severity) = sms.min_severity {
+        if !notification
+            .severity
+            .map_or(false, |severity| severity <= min_severity)
+        {
+            return Ok((
+                AlertStatus::Success,
+                APIResponse {
+                    code: "Skipped".to_string(),
+                    message: format!("severity is below {}", min_severity),
+                    request_id: None,
+                    biz_id: None,
+                },
+            ));
+        }
+    }
+
+    let message = Message {
+        phone_numbers: api_key
+            .split('|')
+            .filter(|number| !number.is_empty())
+            .map(str::to_string)
+            .collect(),
+        sign_name: sms.sign_name.clone(),
+        template_code: sms.template_code.clone(),
+        template_param: render_params(&sms.template_param, notification)?,
+    };
+    if message.phone_numbers.is_empty() {
+        return Err("no phone number in the alicloud_sms api key".into());
+    }
+    info!("Notify Alibaba Cloud SMS: {:?}", message);
+
+    let response: APIResponse = call(
+        alicloud,
+        &sms.endpoint,
+        "SendSms",
+        SMS_API_VERSION,
+        message.params(),
+    )
+    .await?;
+    match response.code.as_str() {
+        "OK" => Ok((AlertStatus::Success, response)),
+        _ => Ok((AlertStatus::Failed, response)),
+    }
+}
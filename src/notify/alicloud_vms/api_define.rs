@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+pub const VMS_API_VERSION: &str = "2017-05-25";
+
+// {"Code": "OK", "Message": "OK", "RequestId": "D9CB3933-9FE3-4870-BA8E-2BEE91B69D23", "CallId": "116012354148^10281378****"}
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct APIResponse {
+    pub code: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
@@ -0,0 +1,35 @@
+use std::collections::BTreeMap;
+
+// The parameters of `SingleCallByTts`, `TtsParam` fills the `${name}` variables of the template:
+// CalledShowNumber=057100000000
+// CalledNumber=13800000000
+// TtsCode=TTS_123456789
+// TtsParam={"title":"High CPU"}
+// PlayTimes=3
+#[derive(Debug)]
+pub struct Message {
+    pub called_show_number: Option<String>,
+    pub called_number: String,
+    pub tts_code: String,
+    pub tts_param: BTreeMap<String, String>,
+    pub play_times: Option<u8>,
+}
+
+impl Message {
+    pub fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("CalledNumber", self.called_number.clone()),
+            ("TtsCode", self.tts_code.clone()),
+        ];
+        if let Some(ref called_show_number) = self.called_show_number {
+            params.push(("CalledShowNumber", called_show_number.clone()));
+        }
+        if !self.tts_param.is_empty() {
+            params.push(("TtsParam", serde_json::to_string(&self.tts_param).unwrap()));
+        }
+        if let Some(play_times) = self.play_times {
+            params.push(("PlayTimes", play_times.to_string()));
+        }
+        params
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    common::AlertStatus,
+    config::config,
+    notify::{
+        alicloud_rpc::call,
+        alicloud_sms::post::render_params,
+        alicloud_vms::{
+            api_define::{APIResponse, VMS_API_VERSION},
+            message::Message,
+        },
+        Notification, NotificationStatus,
+    },
+};
+
+fn skipped(message: String) -> (AlertStatus, APIResponse) {
+    (
+        AlertStatus::Success,
+        APIResponse {
+            code: "Skipped".to_string(),
+            message,
+            request_id: None,
+            call_id: None,
+        },
+    )
+}
+
+// api_key format: "<PHONE_NUMBER>", a call places to one number, so list a key per person. Nobody
+// is woken up for a recovery, and alerts less severe than `min_severity` are skipped, as are plain
+// messages without a severity.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let alicloud = config()
+        .alicloud
+        .as_ref()
+        .ok_or("alicloud_vms destination needs the `alicloud` account in the config")?;
+    let vms = alicloud
+        .vms
+        .as_ref()
+        .ok_or("alicloud_vms destination needs the `alicloud.vms` template in the config")?;
+
+    if notification.status == NotificationStatus::Resolved {
+        return Ok(skipped("alert is resolved".to_string()));
+    }
+    if !notification
+        .severity
+        .map_or(false, |severity| severity <= vms.min_severity)
+    {
+        return Ok(skipped(format!("severity is below {}", vms.min_severity)));
+    }
+
+    let message = Message {
+        called_show_number: vms.called_show_number.clone(),
+        called_number: api_key,
+        tts_code: vms.tts_code.clone(),
+        tts_param: render_params(&vms.tts_param, notification)?,
+        play_times: vms.play_times,
+    };
+    info!("Notify Alibaba Cloud VMS: {:?}", message);
+
+    let response: APIResponse = call(
+        alicloud,
+        &vms.endpoint,
+        "SingleCallByTts",
+        VMS_API_VERSION,
+        message.params(),
+    )
+    .await?;
+    match response.code.as_str() {
+        "OK" => Ok((AlertStatus::Success, response)),
+        _ => Ok((AlertStatus::Failed, response)),
+    }
+}
@@ -0,0 +1,55 @@
+use serde::Serialize;
+
+// {
+// 	"msgtype": "actionCard",
+// 	"actionCard": {
+// 		"title": "Title",
+// 		"text": "#### Title\n content",
+// 		"btnOrientation": "0",
+// 		"singleTitle": "View",
+// 		"singleURL": "https://example.com"
+// 	}
+// }
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    pub msgtype: MessageType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<Markdown>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action_card: Option<ActionCard>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MessageType {
+    Markdown,
+    ActionCard,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Markdown {
+    pub title: String,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ActionCardButton {
+    pub title: String,
+    #[serde(rename = "actionURL")]
+    pub action_url: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionCard {
+    pub title: String,
+    pub text: String,
+    pub btn_orientation: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub single_title: Option<String>,
+    #[serde(rename = "singleURL", skip_serializing_if = "Option::is_none")]
+    pub single_url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub btns: Vec<ActionCardButton>,
+}
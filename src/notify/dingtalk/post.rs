@@ -0,0 +1,188 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use openssl::{hash::MessageDigest, pkey::PKey, sign::Signer};
+use reqwest::Client;
+
+use crate::{
+    common::AlertStatus,
+    notify::{
+        dingtalk::{
+            api_define::APIResponse,
+            message::{ActionCard, ActionCardButton, Markdown, Message, MessageType},
+        },
+        template_color_hex, Notification,
+    },
+};
+
+const DINGTALK_ROBOT_API: &str = "https://oapi.dingtalk.com/robot/send";
+
+// DingTalk markdown needs two trailing spaces for a line break.
+fn markdown_text(notification: &Notification) -> String {
+    let mut lines = vec![match notification.template {
+        Some(ref template) => format!(
+            "#### <font color={}>{}</font>",
+            template_color_hex(template),
+            notification.title
+        ),
+        None => format!("#### {}", notification.title),
+    }];
+    lines.extend(notification.content.lines().map(str::to_string));
+    lines.extend(
+        notification
+            .fields
+            .iter()
+            .map(|field| format!("**{}**: {}", field.name, field.value)),
+    );
+    if let Some(ref image) = notification.image {
+        lines.push(format!("![image]({})", image));
+    }
+    if let Some(ref note) = notification.note {
+        lines.push("".to_string());
+        lines.extend(note.lines().map(|line| format!("> {}", line)));
+    }
+    lines.join("  \n")
+}
+
+pub fn notify(notification: &Notification) -> Message {
+    let text = markdown_text(notification);
+    let mut buttons = notification
+        .url
+        .iter()
+        .map(|url| ActionCardButton {
+            title: "View".to_string(),
+            action_url: url.clone(),
+        })
+        .chain(notification.buttons.iter().map(|button| ActionCardButton {
+            title: button.text.clone(),
+            action_url: button.url.clone(),
+        }))
+        .collect::<Vec<ActionCardButton>>();
+
+    if buttons.is_empty() {
+        return Message {
+            msgtype: MessageType::Markdown,
+            markdown: Some(Markdown {
+                title: notification.title.clone(),
+                text,
+            }),
+            action_card: None,
+        };
+    }
+
+    let (single_title, single_url) = match buttons.len() {
+        1 => {
+            let button = buttons.remove(0);
+            (Some(button.title), Some(button.action_url))
+        }
+        _ => (None, None),
+    };
+    Message {
+        msgtype: MessageType::ActionCard,
+        markdown: None,
+        action_card: Some(ActionCard {
+            title: notification.title.clone(),
+            text,
+            btn_orientation: "0".to_string(),
+            single_title,
+            single_url,
+            btns: buttons,
+        }),
+    }
+}
+
+// sign = base64(HmacSHA256("<timestamp>\n<secret>", secret))
+fn sign(timestamp: u128, secret: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let key = PKey::hmac(secret.as_bytes())?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+    let signature = signer.sign_oneshot_to_vec(format!("{}\n{}", timestamp, secret).as_bytes())?;
+    Ok(openssl::base64::encode_block(&signature))
+}
+
+// api_key format: "<ACCESS_TOKEN>" or "<ACCESS_TOKEN>:<SECRET>" when the robot signs requests.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let message = notify(notification);
+    info!(
+        "Notify DingTalk: {}",
+        serde_json::to_string(&message).unwrap()
+    );
+
+    let (access_token, secret) = match api_key.split_once(':') {
+        Some((access_token, secret)) => (access_token.to_string(), Some(secret.to_string())),
+        None => (api_key, None),
+    };
+    let mut query = vec![("access_token", access_token)];
+    if let Some(secret) = secret {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+        query.push(("sign", sign(timestamp, &secret)?));
+        query.push(("timestamp", timestamp.to_string()));
+    }
+
+    let client = Client::new();
+    let response = client
+        .post(DINGTALK_ROBOT_API)
+        .query(&query)
+        .json(&message)
+        .send()
+        .await?;
+
+    match response.status() {
+        reqwest::StatusCode::OK => {
+            let response: APIResponse = response.json().await?;
+            let status = match response.errcode {
+                0 => AlertStatus::Success,
+                _ => AlertStatus::Failed,
+            };
+            Ok((status, response))
+        }
+        status_code => Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!(
+                "Failed to send DingTalk API request, status: {}",
+                status_code
+            ),
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sign_timestamp() {
+        assert_eq!(
+            sign(1660000000000, "SECabc").unwrap(),
+            "XLwl0/Ie8K/aUhpzwPof/dAIBasRKxZlgglQdb4djg0="
+        );
+    }
+
+    #[test]
+    fn markdown_or_action_card() {
+        let notification = Notification {
+            title: "Disk full".to_string(),
+            content: "db-1\nat 95%".to_string(),
+            note: Some("by cron".to_string()),
+            ..Default::default()
+        };
+        let message = notify(&notification);
+        assert!(message.action_card.is_none());
+        assert_eq!(
+            message.markdown.unwrap().text,
+            "#### Disk full  \ndb-1  \nat 95%  \n  \n> by cron"
+        );
+
+        let notification = Notification {
+            url: Some("https://example.com/alert".to_string()),
+            ..notification
+        };
+        let card = notify(&notification).action_card.unwrap();
+        assert_eq!(card.single_title.as_deref(), Some("View"));
+        assert_eq!(
+            card.single_url.as_deref(),
+            Some("https://example.com/alert")
+        );
+    }
+}
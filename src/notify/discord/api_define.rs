@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+// With `wait=true` the created message is returned:
+// {
+// 	"id": "1085539452379635752",
+// 	"channel_id": "1085532912345678901",
+// 	...
+// }
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APISuccessResponse {
+    pub id: String,
+    pub channel_id: String,
+}
+
+// {
+// 	"message": "Invalid Webhook Token",
+// 	"code": 50027
+// }
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APIErrorResponse {
+    pub message: String,
+    pub code: u32,
+}
+
+// {
+// 	"message": "You are being rate limited.",
+// 	"retry_after": 0.5,
+// 	"global": false
+// }
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APIRateLimitResponse {
+    pub message: String,
+    pub retry_after: f64,
+}
+
+// Untagged variants are tried in order, rate limits first as their body may also carry a `code`.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum APIResponse {
+    Success(APISuccessResponse),
+    RateLimit(APIRateLimitResponse),
+    Error(APIErrorResponse),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+// {
+// 	"embeds": [{
+// 		"title": "Title",
+// 		"description": "**bold** content",
+// 		"url": "https://example.com",
+// 		"color": 16075333,
+// 		"timestamp": "2022-08-01T08:00:00Z",
+// 		"fields": [{ "name": "Size", "value": "12 GiB", "inline": true }],
+// 		"footer": { "text": "note" }
+// 	}]
+// }
+#[derive(Debug, Serialize)]
+pub struct Message {
+    pub embeds: Vec<Embed>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EmbedField {
+    pub name: String,
+    pub value: String,
+    pub inline: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EmbedFooter {
+    pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EmbedImage {
+    pub url: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Embed {
+    pub title: String,
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<u32>,
+    pub timestamp: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<EmbedField>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<EmbedImage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<EmbedFooter>,
+}
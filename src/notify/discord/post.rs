@@ -0,0 +1,217 @@
+use std::time::Duration;
+
+use reqwest::{header::RETRY_AFTER, Client, StatusCode};
+
+use crate::{
+    common::AlertStatus,
+    notify::{
+        discord::{
+            api_define::{APIRateLimitResponse, APIResponse},
+            message::{Embed, EmbedField, EmbedFooter, EmbedImage, Message},
+        },
+        template_color_hex, truncate, utc_timestamp, Notification,
+    },
+};
+
+const DISCORD_WEBHOOK_PREFIX: &str = "https://discord.com/api/webhooks/";
+const MAX_TITLE_LENGTH: usize = 256;
+const MAX_DESCRIPTION_LENGTH: usize = 4096;
+const MAX_FIELDS: usize = 25;
+const MAX_FIELD_VALUE_LENGTH: usize = 1024;
+const MAX_FOOTER_LENGTH: usize = 2048;
+// Title, description, field names and values and footer together.
+const MAX_EMBED_LENGTH: usize = 6000;
+const MAX_RETRIES: usize = 3;
+const MAX_RETRY_AFTER: f64 = 30.0;
+
+// Cut to `max_length` and to what is left of the embed total.
+fn cut(text: &str, max_length: usize, remaining: &mut usize) -> String {
+    let text = truncate(text, max_length.min(*remaining));
+    *remaining -= text.chars().count();
+    text
+}
+
+// Webhooks that do not belong to an application can not send buttons, so they become links
+// at the end of the description. Parts are cut in order against what is left of the total, and
+// fields that no longer fit are dropped.
+pub fn notify(notification: &Notification) -> Message {
+    let mut description = notification.content.clone();
+    if !notification.buttons.is_empty() {
+        let links = notification
+            .buttons
+            .iter()
+            .map(|button| format!("[{}]({})", button.text, button.url))
+            .collect::<Vec<String>>()
+            .join(" · ");
+        description = format!("{}\n\n{}", description, links);
+    }
+
+    let mut remaining = MAX_EMBED_LENGTH;
+    let title = cut(&notification.title, MAX_TITLE_LENGTH, &mut remaining);
+    let description = cut(&description, MAX_DESCRIPTION_LENGTH, &mut remaining);
+    let mut fields = Vec::new();
+    for field in notification.fields.iter().take(MAX_FIELDS) {
+        // A field needs its whole name and some of its value.
+        let name = truncate(&field.name, MAX_TITLE_LENGTH);
+        if name.chars().count() >= remaining {
+            break;
+        }
+        remaining -= name.chars().count();
+        fields.push(EmbedField {
+            name,
+            value: cut(&field.value, MAX_FIELD_VALUE_LENGTH, &mut remaining),
+            inline: field.short,
+        });
+    }
+    let footer = notification
+        .note
+        .as_ref()
+        .map(|note| cut(note, MAX_FOOTER_LENGTH, &mut remaining))
+        .filter(|note| !note.is_empty())
+        .map(|text| EmbedFooter { text });
+
+    let embed = Embed {
+        title,
+        description,
+        url: notification.url.clone(),
+        color: notification.template.as_ref().map(|template| {
+            u32::from_str_radix(template_color_hex(template).trim_start_matches('#'), 16).unwrap()
+        }),
+        timestamp: utc_timestamp(),
+        fields,
+        image: notification
+            .image
+            .as_ref()
+            .map(|url| EmbedImage { url: url.clone() }),
+        footer,
+    };
+
+    Message {
+        embeds: vec![embed],
+    }
+}
+
+// api_key format: "<WEBHOOK_ID>/<WEBHOOK_TOKEN>", the path after
+// `https://discord.com/api/webhooks/`.
+// Rate limited requests are retried after the `retry_after` seconds Discord asks for.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let message = notify(notification);
+    info!(
+        "Notify Discord: {}",
+        serde_json::to_string(&message).unwrap()
+    );
+
+    let client = Client::new();
+    let mut retries = 0;
+    loop {
+        let response = client
+            .post(&format!("{}{}", DISCORD_WEBHOOK_PREFIX, api_key))
+            .query(&[("wait", "true")])
+            .json(&message)
+            .send()
+            .await?;
+
+        let status_code = response.status();
+        if status_code.is_server_error() {
+            return Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::Other,
+                format!(
+                    "Failed to send Discord API request, status: {}",
+                    status_code
+                ),
+            )));
+        }
+
+        if status_code != StatusCode::TOO_MANY_REQUESTS {
+            let response: APIResponse = response.json().await?;
+            return match response {
+                APIResponse::Success(_) => Ok((AlertStatus::Success, response)),
+                _ => Ok((AlertStatus::Failed, response)),
+            };
+        }
+
+        // A limit hit at the Cloudflare edge only comes with the `Retry-After` header.
+        let retry_after_header = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<f64>().ok());
+        let text = response.text().await?;
+        let rate_limit = match (
+            serde_json::from_str::<APIRateLimitResponse>(&text),
+            retry_after_header,
+        ) {
+            (Ok(rate_limit), _) => rate_limit,
+            (Err(_), Some(retry_after)) => APIRateLimitResponse {
+                message: text,
+                retry_after,
+            },
+            (Err(err), None) => return Err(Box::new(err)),
+        };
+        if retries >= MAX_RETRIES || rate_limit.retry_after > MAX_RETRY_AFTER {
+            return Ok((AlertStatus::Failed, APIResponse::RateLimit(rate_limit)));
+        }
+        warn!(
+            "Discord rate limited, retry after {}s",
+            rate_limit.retry_after
+        );
+        retries += 1;
+        tokio::time::sleep(Duration::from_secs_f64(rate_limit.retry_after.max(0.0))).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notify::NotificationField;
+
+    fn embed_length(embed: &Embed) -> usize {
+        embed.title.chars().count()
+            + embed.description.chars().count()
+            + embed
+                .fields
+                .iter()
+                .map(|field| field.name.chars().count() + field.value.chars().count())
+                .sum::<usize>()
+            + embed
+                .footer
+                .as_ref()
+                .map_or(0, |footer| footer.text.chars().count())
+    }
+
+    #[test]
+    fn cap_embed_total() {
+        let notification = Notification {
+            title: "t".repeat(300),
+            content: "d".repeat(5000),
+            fields: (0..10)
+                .map(|i| NotificationField {
+                    name: format!("field {}", i),
+                    value: "v".repeat(1000),
+                    short: false,
+                })
+                .collect(),
+            note: Some("n".repeat(100)),
+            ..Default::default()
+        };
+        let message = notify(&notification);
+        let embed = &message.embeds[0];
+        assert_eq!(embed.title.len(), MAX_TITLE_LENGTH);
+        assert_eq!(embed.description.len(), MAX_DESCRIPTION_LENGTH);
+        assert_eq!(embed.fields.len(), 2);
+        assert_eq!(embed.fields[1].value.len(), 6000 - 256 - 4096 - 1007 - 7);
+        assert!(embed.footer.is_none());
+        assert_eq!(embed_length(embed), MAX_EMBED_LENGTH);
+
+        let notification = Notification {
+            fields: vec![],
+            ..notification
+        };
+        let embed = &notify(&notification).embeds[0];
+        assert_eq!(embed.footer.as_ref().unwrap().text.len(), 100);
+        assert!(embed_length(embed) <= MAX_EMBED_LENGTH);
+    }
+}
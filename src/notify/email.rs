@@ -0,0 +1,3 @@
+pub(crate) mod api_define;
+pub(crate) mod post;
+pub(crate) mod template;
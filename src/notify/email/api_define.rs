@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+// The reply of the relay to the message, e.g. `250 2.0.0 Ok: queued as 4C1F12A0`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APIResponse {
+    pub code: u16,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
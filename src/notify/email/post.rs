@@ -0,0 +1,139 @@
+use lettre::{
+    message::{Mailbox, MultiPart},
+    transport::smtp::{authentication::Credentials, response::Response},
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+
+use crate::{
+    common::AlertStatus,
+    config::{config, EmailConfig, EmailSecurity},
+    notify::{
+        email::{
+            api_define::APIResponse,
+            template::{html, text},
+        },
+        Notification,
+    },
+};
+
+fn mailbox(address: &str) -> Result<Mailbox, String> {
+    address
+        .parse::<Mailbox>()
+        .map_err(|err| format!("invalid email address `{}`: {}", address, err))
+}
+
+// `user@example.com|management` => every address, with the groups expanded from the config.
+// Addresses outside the groups must be on one of the `allowed_domains`.
+fn recipients(api_key: &str, email: &EmailConfig) -> Result<Vec<Mailbox>, String> {
+    let mut recipients = Vec::new();
+    for recipient in api_key.split('|').filter(|recipient| !recipient.is_empty()) {
+        match recipient.contains('@') {
+            true => {
+                let mailbox = mailbox(recipient)?;
+                let domain = mailbox.email.domain();
+                if !email
+                    .allowed_domains
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(domain))
+                {
+                    return Err(format!("email domain `{}` is not allowed", domain));
+                }
+                recipients.push(mailbox);
+            }
+            false => {
+                let addresses = email
+                    .groups
+                    .get(recipient)
+                    .ok_or(format!("unknown email group `{}`", recipient))?;
+                for address in addresses {
+                    recipients.push(mailbox(address)?);
+                }
+            }
+        }
+    }
+    match recipients.is_empty() {
+        true => Err("no email recipient".to_string()),
+        false => Ok(recipients),
+    }
+}
+
+fn transport(
+    email: &EmailConfig,
+) -> Result<AsyncSmtpTransport<Tokio1Executor>, Box<dyn std::error::Error>> {
+    let builder = match email.security {
+        EmailSecurity::Starttls => {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&email.host)?
+        }
+        EmailSecurity::Tls => AsyncSmtpTransport::<Tokio1Executor>::relay(&email.host)?,
+        EmailSecurity::None => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&email.host),
+    };
+    let builder = match email.port {
+        Some(port) => builder.port(port),
+        None => builder,
+    };
+    let builder = match (&email.username, &email.password) {
+        (Some(username), Some(password)) => {
+            builder.credentials(Credentials::new(username.clone(), password.clone()))
+        }
+        _ => builder,
+    };
+    Ok(builder.build())
+}
+
+fn api_response(response: &Response) -> APIResponse {
+    APIResponse {
+        code: response.code().to_string().parse().unwrap_or_default(),
+        message: response.message().collect::<Vec<&str>>().join(" "),
+    }
+}
+
+// api_key format: "<ADDRESS>|<ADDRESS>|<GROUP>", where a group is one of `email.groups` in the
+// config and an address is on one of its `email.allowed_domains`. A recipient refused by the relay
+// fails the notification, not the request.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let email = config()
+        .email
+        .as_ref()
+        .ok_or("email destination needs the `email` relay in the config")?;
+
+    let mut builder = Message::builder()
+        .from(email.from.parse()?)
+        .subject(&notification.title);
+    for recipient in recipients(&api_key, email)? {
+        builder = builder.to(recipient);
+    }
+    let message = builder.multipart(MultiPart::alternative_plain_html(
+        text(notification),
+        html(notification),
+    ))?;
+    info!(
+        "Notify email: {} to {}",
+        notification.title,
+        message
+            .envelope()
+            .to()
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    );
+
+    let transport = transport(email)?;
+    match transport.send(message).await {
+        Ok(response) => Ok((AlertStatus::Success, api_response(&response))),
+        Err(err) if err.is_permanent() || err.is_transient() => Ok((
+            AlertStatus::Failed,
+            APIResponse {
+                code: err
+                    .status()
+                    .and_then(|code| code.to_string().parse().ok())
+                    .unwrap_or_default(),
+                message: err.to_string(),
+            },
+        )),
+        Err(err) => Err(Box::new(err)),
+    }
+}
@@ -0,0 +1,150 @@
+use crate::notify::{
+    escape_html, lark_md_to_html, lark_md_to_plain_text, template_color_hex, Notification,
+};
+
+const DEFAULT_COLOR: &str = "#3370FF";
+
+fn button(text: &str, url: &str, color: &str) -> String {
+    format!(
+        r#"<a href="{}" style="display:inline-block;margin:0 8px 8px 0;padding:6px 14px;border-radius:4px;background:{};color:#FFFFFF;text-decoration:none">{}</a>"#,
+        escape_html(url),
+        color,
+        escape_html(text)
+    )
+}
+
+// Inline styles only, mail clients drop <style> blocks.
+pub fn html(notification: &Notification) -> String {
+    let color = notification
+        .template
+        .as_ref()
+        .map(template_color_hex)
+        .unwrap_or(DEFAULT_COLOR);
+
+    let mut sections = vec![
+        format!(
+            r#"<h2 style="margin:0 0 12px;font-size:18px">{}</h2>"#,
+            escape_html(&notification.title)
+        ),
+        format!(
+            r#"<p style="margin:0 0 12px;line-height:1.5">{}</p>"#,
+            lark_md_to_html(&notification.content).replace('\n', "<br>")
+        ),
+    ];
+    if !notification.fields.is_empty() {
+        let rows = notification
+            .fields
+            .iter()
+            .map(|field| {
+                format!(
+                    r#"<tr><td style="padding:4px 16px 4px 0;color:#646A73">{}</td><td style="padding:4px 0">{}</td></tr>"#,
+                    escape_html(&field.name),
+                    lark_md_to_html(&field.value)
+                )
+            })
+            .collect::<String>();
+        sections.push(format!(
+            r#"<table style="margin:0 0 12px;border-collapse:collapse">{}</table>"#,
+            rows
+        ));
+    }
+    if let Some(ref image) = notification.image {
+        sections.push(format!(
+            r#"<p style="margin:0 0 12px"><img src="{}" alt="" style="max-width:100%"></p>"#,
+            escape_html(image)
+        ));
+    }
+
+    let buttons = notification
+        .url
+        .iter()
+        .map(|url| button("View", url, color))
+        .chain(
+            notification
+                .buttons
+                .iter()
+                .map(|b| button(&b.text, &b.url, DEFAULT_COLOR)),
+        )
+        .collect::<String>();
+    if !buttons.is_empty() {
+        sections.push(format!(r#"<p style="margin:0 0 4px">{}</p>"#, buttons));
+    }
+    if let Some(ref note) = notification.note {
+        sections.push(format!(
+            r#"<p style="margin:8px 0 0;font-size:12px;color:#8F959E">{}</p>"#,
+            lark_md_to_html(note).replace('\n', "<br>")
+        ));
+    }
+
+    format!(
+        r#"<!DOCTYPE html><html><body style="margin:0;padding:16px;font-family:-apple-system,'Segoe UI',Helvetica,Arial,sans-serif;font-size:14px;color:#1F2329"><div style="max-width:640px;border-left:4px solid {};padding:12px 16px;background:#F7F8FA">{}</div></body></html>"#,
+        color,
+        sections.join("")
+    )
+}
+
+pub fn text(notification: &Notification) -> String {
+    let mut lines = vec![
+        notification.title.clone(),
+        "".to_string(),
+        lark_md_to_plain_text(&notification.content),
+    ];
+    if !notification.fields.is_empty() {
+        lines.push("".to_string());
+        lines.extend(
+            notification
+                .fields
+                .iter()
+                .map(|field| format!("{}: {}", field.name, lark_md_to_plain_text(&field.value))),
+        );
+    }
+    let links = notification
+        .url
+        .iter()
+        .map(|url| format!("View: {}", url))
+        .chain(
+            notification
+                .buttons
+                .iter()
+                .map(|button| format!("{}: {}", button.text, button.url)),
+        )
+        .collect::<Vec<String>>();
+    if !links.is_empty() {
+        lines.push("".to_string());
+        lines.extend(links);
+    }
+    if let Some(ref note) = notification.note {
+        lines.push("".to_string());
+        lines.push(lark_md_to_plain_text(note));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notify::{NotificationButton, NotificationField};
+
+    #[test]
+    fn html_escapes_and_converts() {
+        let html = html(&Notification {
+            title: "<script>".to_string(),
+            content: "**db-01**\ndown".to_string(),
+            fields: vec![NotificationField {
+                name: "a&b".to_string(),
+                value: "~~1~~".to_string(),
+                short: false,
+            }],
+            buttons: vec![NotificationButton {
+                text: "Runbook".to_string(),
+                url: "https://wiki/x?a=\"1\"".to_string(),
+                r#type: None,
+            }],
+            ..Default::default()
+        });
+        assert!(html.contains(">&lt;script&gt;</h2>"));
+        assert!(html.contains("<b>db-01</b><br>down"));
+        assert!(html.contains(">a&amp;b</td><td style=\"padding:4px 0\"><s>1</s></td>"));
+        assert!(html.contains("href=\"https://wiki/x?a=&quot;1&quot;\""));
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use strum::EnumString;
 
 const fn default_true() -> bool {
     true
@@ -48,8 +49,9 @@ pub enum CardTitle {
     I18n(CardTitleI18n),
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, EnumString)]
 #[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
 pub enum TemplateColor {
     Green,
     #[serde(rename = "green")]
@@ -149,7 +151,7 @@ pub struct CardHref {
     pub url_val: CardMultiUrl,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum CardButtonType {
     Default,
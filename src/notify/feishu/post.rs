@@ -1,12 +1,15 @@
 use crate::{
     common::AlertStatus,
-    notify::feishu::{
-        api_define::APIResponse,
-        card::{
-            ActionElement, ActionLayout, Card, CardButton, CardButtonType, CardHeader, CardText,
-            CardTitle, CardTitlePlainText, Message, Module, NoteElement, TemplateColor,
-            TextElement,
+    notify::{
+        feishu::{
+            api_define::APIResponse,
+            card::{
+                ActionElement, ActionLayout, Card, CardButton, CardButtonType, CardField,
+                CardHeader, CardText, CardTitle, CardTitlePlainText, Message, Module, NoteElement,
+                TemplateColor, TextElement,
+            },
         },
+        Notification,
     },
 };
 
@@ -14,63 +17,102 @@ use reqwest::Client;
 
 const FEISHU_OPEN_API_PREFIX: &str = "https://open.feishu.cn/open-apis/bot/v2/hook/";
 
-pub fn notify(
-    title: String,
-    url: String,
-    content: String,
-    template: Option<TemplateColor>,
-) -> Message {
+// Fields go below the content, and extra buttons after the "View" one. The action and note
+// sections are left out when there is no button.
+pub fn notify(notification: &Notification) -> Message {
     let config = super::card::CardConfig {
         enable_forward: true,
         update_multi: false,
     };
     let header = CardHeader {
         title: CardTitle::PlainText(CardTitlePlainText::PlainText(TextElement {
-            content: title,
+            content: notification.title.clone(),
             ..Default::default()
         })),
-        template: template.unwrap_or(TemplateColor::Blue),
+        template: notification.template.clone().unwrap_or(TemplateColor::Blue),
     };
 
+    let fields = notification
+        .fields
+        .iter()
+        .map(|field| CardField {
+            is_short: field.short,
+            text: CardText::LarkMd(TextElement {
+                content: format!("**{}**\n{}", field.name, field.value),
+                ..Default::default()
+            }),
+        })
+        .collect::<Vec<CardField>>();
     let div = Module::Div {
         text: Some(CardText::LarkMd(TextElement {
-            content,
+            content: notification.content.clone(),
             ..Default::default()
         })),
-        fields: None,
+        fields: match fields.is_empty() {
+            true => None,
+            false => Some(fields),
+        },
         extra: None,
     };
 
-    let hr = Module::Hr;
-
-    let action = Module::Action {
-        actions: vec![ActionElement::Button(CardButton {
-            text: CardText::LarkMd(TextElement {
-                content: "View".to_string(),
+    let mut elements = vec![div];
+    if let Some(ref note) = notification.note {
+        elements.push(Module::Note {
+            elements: vec![NoteElement::LarkMd(TextElement {
+                content: note.clone(),
                 ..Default::default()
-            }),
-            url: Some(url),
-            multi_url: None,
-            r#type: Some(CardButtonType::Primary),
-            value: None,
-            confirm: None,
-        })],
-        layout: Some(ActionLayout::Flow),
-    };
+            })],
+        });
+    }
 
-    let note = Module::Note {
-        elements: vec![NoteElement::PlainText(TextElement {
-            content: "Note: You may need related permissions to open the buttons above."
-                .to_string(),
+    let view = notification.url.as_ref().map(|url| CardButton {
+        text: CardText::LarkMd(TextElement {
+            content: "View".to_string(),
             ..Default::default()
-        })],
-    };
+        }),
+        url: Some(url.clone()),
+        multi_url: None,
+        r#type: Some(CardButtonType::Primary),
+        value: None,
+        confirm: None,
+    });
+    let buttons = notification.buttons.iter().map(|button| CardButton {
+        text: CardText::LarkMd(TextElement {
+            content: button.text.clone(),
+            ..Default::default()
+        }),
+        url: Some(button.url.clone()),
+        multi_url: None,
+        r#type: Some(button.r#type.clone().unwrap_or(CardButtonType::Default)),
+        value: None,
+        confirm: None,
+    });
+    let actions = view
+        .into_iter()
+        .chain(buttons)
+        .map(ActionElement::Button)
+        .collect::<Vec<ActionElement>>();
+
+    if !actions.is_empty() {
+        elements.push(Module::Hr);
+        elements.push(Module::Action {
+            actions,
+            layout: Some(ActionLayout::Flow),
+        });
+        elements.push(Module::Note {
+            elements: vec![NoteElement::PlainText(TextElement {
+                content: "Note: You may need related permissions to open the buttons above."
+                    .to_string(),
+                ..Default::default()
+            })],
+        });
+    }
 
     let card = Card {
         config,
         header,
         i18n_elements: None,
-        elements: Some(vec![div, hr, action, note]),
+        elements: Some(elements),
     };
 
     Message::Interactive(card)
@@ -78,12 +120,9 @@ pub fn notify(
 
 pub async fn post(
     api_key: String,
-    title: String,
-    url: String,
-    content: String,
-    template: Option<TemplateColor>,
+    notification: &Notification,
 ) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
-    let message = notify(title, url, content, template);
+    let message = notify(notification);
     info!(
         "Notify Feishu: {}",
         serde_json::to_string(&message).unwrap()
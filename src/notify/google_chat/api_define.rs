@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Thread {
+    pub name: String,
+}
+
+// The created message, such as `spaces/AAAA/messages/BBBB`, and the thread it is in.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SuccessResponse {
+    pub name: String,
+    pub thread: Option<Thread>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Error {
+    pub code: u16,
+    pub message: String,
+    pub status: String,
+}
+
+// {"error": {"code": 400, "message": "Invalid JSON payload received.", "status": "INVALID_ARGUMENT"}}
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ErrorResponse {
+    pub error: Error,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum APIResponse {
+    Success(SuccessResponse),
+    Error(ErrorResponse),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
@@ -0,0 +1,123 @@
+use serde::Serialize;
+
+// {
+//   "cardsV2": [{
+//     "cardId": "alert",
+//     "card": {
+//       "header": { "title": "Title", "subtitle": "firing · critical" },
+//       "sections": [{
+//         "widgets": [
+//           { "textParagraph": { "text": "<b>bold</b> content" } },
+//           { "decoratedText": { "topLabel": "Size", "text": "12 GiB", "wrapText": true } },
+//           { "buttonList": { "buttons": [{
+//             "text": "View",
+//             "onClick": { "openLink": { "url": "https://example.com" } },
+//             "color": { "red": 0.96, "green": 0.29, "blue": 0.27, "alpha": 1 }
+//           }] } }
+//         ]
+//       }]
+//     }
+//   }],
+//   "thread": { "threadKey": "grafana-1-42" }
+// }
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    #[serde(rename = "cardsV2")]
+    pub cards_v2: Vec<CardWithId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread: Option<Thread>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Thread {
+    pub thread_key: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardWithId {
+    pub card_id: String,
+    pub card: Card,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Card {
+    pub header: CardHeader,
+    pub sections: Vec<Section>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CardHeader {
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subtitle: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Section {
+    pub widgets: Vec<Widget>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Widget {
+    TextParagraph(TextParagraph),
+    DecoratedText(DecoratedText),
+    Image(Image),
+    ButtonList(ButtonList),
+}
+
+#[derive(Debug, Serialize)]
+pub struct TextParagraph {
+    pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecoratedText {
+    pub top_label: String,
+    pub text: String,
+    pub wrap_text: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Image {
+    pub image_url: String,
+    pub alt_text: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ButtonList {
+    pub buttons: Vec<Button>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Button {
+    pub text: String,
+    pub on_click: OnClick,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<Color>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnClick {
+    pub open_link: OpenLink,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OpenLink {
+    pub url: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Color {
+    pub red: f32,
+    pub green: f32,
+    pub blue: f32,
+    pub alpha: f32,
+}
@@ -0,0 +1,169 @@
+use reqwest::Client;
+
+use crate::{
+    common::AlertStatus,
+    notify::{
+        check_url_host,
+        feishu::card::{CardButtonType, TemplateColor},
+        google_chat::{
+            api_define::APIResponse,
+            message::{
+                Button, ButtonList, Card, CardHeader, CardWithId, Color, DecoratedText, Image,
+                Message, OnClick, OpenLink, Section, TextParagraph, Thread, Widget,
+            },
+        },
+        lark_md_to_html, take_url_options, template_color_hex, Notification, NotificationStatus,
+    },
+};
+
+const CARD_ID: &str = "alert";
+const WEBHOOK_HOSTS: [&str; 1] = ["chat.googleapis.com"];
+const URL_OPTIONS: [&str; 1] = ["threadKey"];
+// Reply in the thread of the key, or start it when there is none yet.
+const MESSAGE_REPLY_OPTION: &str = "REPLY_MESSAGE_FALLBACK_TO_NEW_THREAD";
+
+// "#F54A45" => { red: 0.96, green: 0.29, blue: 0.27 }
+fn template_color(template: &TemplateColor) -> Color {
+    let hex = u32::from_str_radix(template_color_hex(template).trim_start_matches('#'), 16)
+        .unwrap_or_default();
+    Color {
+        red: ((hex >> 16) & 0xFF) as f32 / 255.0,
+        green: ((hex >> 8) & 0xFF) as f32 / 255.0,
+        blue: (hex & 0xFF) as f32 / 255.0,
+        alpha: 1.0,
+    }
+}
+
+// Card text is a subset of HTML, with `<br>` for line breaks.
+fn html(text: &str) -> String {
+    lark_md_to_html(text).replace('\n', "<br>")
+}
+
+fn button(text: &str, url: &str, color: Option<Color>) -> Button {
+    Button {
+        text: text.to_string(),
+        on_click: OnClick {
+            open_link: OpenLink {
+                url: url.to_string(),
+            },
+        },
+        color,
+    }
+}
+
+// Alerts show their status and severity, plain messages have no subtitle.
+fn subtitle(notification: &Notification) -> Option<String> {
+    match (notification.status, notification.severity) {
+        (status, Some(severity)) => Some(format!("{} · {}", status, severity)),
+        (NotificationStatus::Resolved, None) => Some(NotificationStatus::Resolved.to_string()),
+        (NotificationStatus::Firing, None) => None,
+    }
+}
+
+pub fn notify(notification: &Notification) -> Message {
+    let mut widgets = vec![Widget::TextParagraph(TextParagraph {
+        text: html(&notification.content),
+    })];
+    widgets.extend(notification.fields.iter().map(|field| {
+        Widget::DecoratedText(DecoratedText {
+            top_label: field.name.clone(),
+            text: html(&field.value),
+            wrap_text: true,
+        })
+    }));
+    if let Some(ref image) = notification.image {
+        widgets.push(Widget::Image(Image {
+            image_url: image.clone(),
+            alt_text: notification.title.clone(),
+        }));
+    }
+    if let Some(ref note) = notification.note {
+        widgets.push(Widget::TextParagraph(TextParagraph {
+            text: format!("<font color=\"#8F959E\">{}</font>", html(note)),
+        }));
+    }
+
+    // The view button takes the color of the alert, the others only stand out when dangerous.
+    let buttons = notification
+        .url
+        .iter()
+        .map(|url| {
+            button(
+                "View",
+                url,
+                notification.template.as_ref().map(template_color),
+            )
+        })
+        .chain(notification.buttons.iter().map(|b| {
+            let color = match b.r#type {
+                Some(CardButtonType::Danger) => Some(template_color(&TemplateColor::Red)),
+                _ => None,
+            };
+            button(&b.text, &b.url, color)
+        }))
+        .collect::<Vec<Button>>();
+    if !buttons.is_empty() {
+        widgets.push(Widget::ButtonList(ButtonList { buttons }));
+    }
+
+    Message {
+        cards_v2: vec![CardWithId {
+            card_id: CARD_ID.to_string(),
+            card: Card {
+                header: CardHeader {
+                    title: notification.title.clone(),
+                    subtitle: subtitle(notification),
+                },
+                sections: vec![Section { widgets }],
+            },
+        }],
+        thread: notification
+            .fingerprint
+            .clone()
+            .map(|thread_key| Thread { thread_key }),
+    }
+}
+
+// api_key format: the whole url of the space webhook, url encoded as it is a query parameter
+// itself. The notifications of an alert share a thread keyed by its fingerprint, or by the
+// `threadKey` query parameter of the url, which puts every notification in one thread.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let (mut url, mut options) = take_url_options(&api_key, &URL_OPTIONS)?;
+    check_url_host(&url, &WEBHOOK_HOSTS)?;
+    let mut message = notify(notification);
+    if let Some(thread_key) = options.remove("threadKey") {
+        message.thread = Some(Thread { thread_key });
+    }
+    if message.thread.is_some() {
+        url.query_pairs_mut()
+            .append_pair("messageReplyOption", MESSAGE_REPLY_OPTION);
+    }
+    info!(
+        "Notify Google Chat: {}",
+        serde_json::to_string(&message).unwrap()
+    );
+
+    let client = Client::new();
+    let response = client.post(url).json(&message).send().await?;
+
+    // Errors such as an invalid card come with a 4xx status and a JSON error.
+    let status_code = response.status();
+    if status_code.is_server_error() {
+        return Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!(
+                "Failed to send Google Chat API request, status: {}",
+                status_code
+            ),
+        )));
+    }
+
+    let response: APIResponse = response.json().await?;
+    match response {
+        APIResponse::Success(_) => Ok((AlertStatus::Success, response)),
+        APIResponse::Error(_) => Ok((AlertStatus::Failed, response)),
+    }
+}
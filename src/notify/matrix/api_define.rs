@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SuccessResponse {
+    pub event_id: String,
+}
+
+// {"errcode": "M_LIMIT_EXCEEDED", "error": "Too many requests", "retry_after_ms": 2000}
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RateLimitResponse {
+    pub errcode: String,
+    pub error: String,
+    pub retry_after_ms: u64,
+}
+
+// {"errcode": "M_FORBIDDEN", "error": "User @bot:example.com not in room !abc:example.com"}
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ErrorResponse {
+    pub errcode: String,
+    #[serde(default)]
+    pub error: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum APIResponse {
+    Success(SuccessResponse),
+    RateLimit(RateLimitResponse),
+    Error(ErrorResponse),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+pub const HTML_FORMAT: &str = "org.matrix.custom.html";
+
+// {
+//   "msgtype": "m.notice",
+//   "body": "Title\n\nbold content",
+//   "format": "org.matrix.custom.html",
+//   "formatted_body": "<h4><font data-mx-color=\"#F54A45\">Title</font></h4><p><b>bold</b> content</p>"
+// }
+#[derive(Debug, Serialize)]
+pub struct Message {
+    pub msgtype: MessageType,
+    pub body: String,
+    pub format: &'static str,
+    pub formatted_body: String,
+}
+
+// Bots send notices, which clients show less prominently and other bots must not answer.
+#[derive(Debug, Serialize)]
+pub enum MessageType {
+    #[serde(rename = "m.notice")]
+    Notice,
+}
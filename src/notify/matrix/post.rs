@@ -0,0 +1,194 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwest::Client;
+
+use crate::{
+    common::AlertStatus,
+    config::config,
+    notify::{
+        escape_html, lark_md_to_html, lark_md_to_plain_text,
+        matrix::{
+            api_define::APIResponse,
+            message::{Message, MessageType, HTML_FORMAT},
+        },
+        template_color_hex, Notification,
+    },
+};
+
+const MAX_RETRIES: usize = 3;
+const RETRY_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_RETRY_AFTER_MS: u64 = 30_000;
+
+static TRANSACTIONS: AtomicU64 = AtomicU64::new(0);
+
+// Unique per message across restarts, and kept by the retries of a message so the homeserver
+// drops the duplicates.
+fn transaction_id() -> String {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or_default();
+    format!(
+        "notify-bridge.{}.{}",
+        millis,
+        TRANSACTIONS.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+fn body(notification: &Notification) -> String {
+    let mut lines = vec![
+        notification.title.clone(),
+        "".to_string(),
+        lark_md_to_plain_text(&notification.content),
+    ];
+    lines.extend(
+        notification
+            .fields
+            .iter()
+            .map(|field| format!("{}: {}", field.name, lark_md_to_plain_text(&field.value))),
+    );
+    lines.extend(notification.url.iter().map(|url| format!("View: {}", url)));
+    lines.extend(
+        notification
+            .buttons
+            .iter()
+            .map(|button| format!("{}: {}", button.text, button.url)),
+    );
+    lines.extend(notification.note.as_deref().map(lark_md_to_plain_text));
+    lines.join("\n")
+}
+
+// Images must be uploaded to the homeserver to be shown inline, so they are linked instead.
+fn formatted_body(notification: &Notification) -> String {
+    let link = |url: &str, text: &str| format!("<a href=\"{}\">{}</a>", escape_html(url), text);
+
+    let mut title = escape_html(&notification.title);
+    if let Some(ref url) = notification.url {
+        title = link(url, &title);
+    }
+    if let Some(ref template) = notification.template {
+        title = format!(
+            "<font data-mx-color=\"{}\">{}</font>",
+            template_color_hex(template),
+            title
+        );
+    }
+
+    let mut html = vec![
+        format!("<h4>{}</h4>", title),
+        format!(
+            "<p>{}</p>",
+            lark_md_to_html(&notification.content).replace('\n', "<br>")
+        ),
+    ];
+    if !notification.fields.is_empty() {
+        html.push(format!(
+            "<ul>{}</ul>",
+            notification
+                .fields
+                .iter()
+                .map(|field| format!(
+                    "<li><b>{}</b>: {}</li>",
+                    escape_html(&field.name),
+                    lark_md_to_html(&field.value)
+                ))
+                .collect::<String>()
+        ));
+    }
+    let links = notification
+        .buttons
+        .iter()
+        .map(|button| link(&button.url, &escape_html(&button.text)))
+        .chain(notification.image.iter().map(|image| link(image, "Image")))
+        .collect::<Vec<String>>();
+    if !links.is_empty() {
+        html.push(format!("<p>{}</p>", links.join(" · ")));
+    }
+    if let Some(ref note) = notification.note {
+        html.push(format!("<p><sub>{}</sub></p>", lark_md_to_html(note)));
+    }
+    html.join("")
+}
+
+pub fn notify(notification: &Notification) -> Message {
+    Message {
+        msgtype: MessageType::Notice,
+        body: body(notification),
+        format: HTML_FORMAT,
+        formatted_body: formatted_body(notification),
+    }
+}
+
+// api_key format: "<ROOM_ID>" such as "!abc:example.com", a room the user of the access token
+// has joined. Rate limited, failed and unanswered requests are retried with the same transaction.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let matrix = config()
+        .matrix
+        .as_ref()
+        .ok_or("matrix destination needs the `matrix` homeserver in the config")?;
+    let message = notify(notification);
+    info!(
+        "Notify Matrix: {}",
+        serde_json::to_string(&message).unwrap()
+    );
+
+    let url = format!(
+        "{}/_matrix/client/v3/rooms/{}/send/m.room.message/{}",
+        matrix.homeserver_url.trim_end_matches('/'),
+        utf8_percent_encode(&api_key, NON_ALPHANUMERIC),
+        transaction_id(),
+    );
+    let client = Client::new();
+    let mut retries = 0;
+    loop {
+        let response = client
+            .put(&url)
+            .bearer_auth(&matrix.access_token)
+            .json(&message)
+            .send()
+            .await;
+
+        let response = match response {
+            Ok(response) if !response.status().is_server_error() => response,
+            _ if retries < MAX_RETRIES => {
+                warn!("Failed to send Matrix API request, retry");
+                retries += 1;
+                tokio::time::sleep(RETRY_INTERVAL).await;
+                continue;
+            }
+            Ok(response) => {
+                return Err(Box::new(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    format!(
+                        "Failed to send Matrix API request, status: {}",
+                        response.status()
+                    ),
+                )))
+            }
+            Err(err) => return Err(Box::new(err)),
+        };
+
+        let response: APIResponse = response.json().await?;
+        match response {
+            APIResponse::Success(_) => return Ok((AlertStatus::Success, response)),
+            APIResponse::RateLimit(ref rate_limit)
+                if retries < MAX_RETRIES && rate_limit.retry_after_ms <= MAX_RETRY_AFTER_MS =>
+            {
+                warn!(
+                    "Matrix rate limited, retry after {}ms",
+                    rate_limit.retry_after_ms
+                );
+                retries += 1;
+                tokio::time::sleep(Duration::from_millis(rate_limit.retry_after_ms)).await;
+            }
+            _ => return Ok((AlertStatus::Failed, response)),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+// Incoming webhooks answer `ok` in plain text, errors come with a 4xx status and a JSON body.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APIResponse {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+// {"id": "web.incoming_webhook.invalid.app_error", "message": "Invalid webhook.", "status_code": 400}
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ErrorResponse {
+    pub id: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
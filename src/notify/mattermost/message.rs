@@ -0,0 +1,50 @@
+use serde::Serialize;
+
+// {
+//   "channel": "alerts",
+//   "username": "Notify Bridge",
+//   "attachments": [{
+//     "fallback": "Title",
+//     "color": "#F54A45",
+//     "title": "Title",
+//     "title_link": "https://example.com",
+//     "text": "**bold** content\n[Runbook](https://example.com/runbook)",
+//     "fields": [{ "title": "Host", "value": "node-1", "short": true }],
+//     "image_url": "https://example.com/graph.png",
+//     "footer": "Note"
+//   }]
+// }
+#[derive(Debug, Serialize)]
+pub struct Message {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+    pub attachments: Vec<Attachment>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Attachment {
+    pub fallback: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_link: Option<String>,
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<Field>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Field {
+    pub title: String,
+    pub value: String,
+    pub short: bool,
+}
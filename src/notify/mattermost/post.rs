@@ -0,0 +1,117 @@
+use reqwest::Client;
+
+use crate::{
+    common::AlertStatus,
+    config::config,
+    notify::{
+        check_url_host, lark_md_to_plain_text,
+        mattermost::{
+            api_define::{APIResponse, ErrorResponse},
+            message::{Attachment, Field, Message},
+        },
+        take_url_options, template_color_hex, Notification,
+    },
+};
+
+const URL_OPTIONS: [&str; 3] = ["channel", "username", "icon_url"];
+
+// Mattermost renders Markdown, which lark_md already is. Buttons become links below the content,
+// as attachment actions can only call back integrations.
+pub fn notify(notification: &Notification) -> Message {
+    let mut text = vec![notification.content.clone()];
+    if !notification.buttons.is_empty() {
+        text.push(
+            notification
+                .buttons
+                .iter()
+                .map(|button| format!("[{}]({})", button.text, button.url))
+                .collect::<Vec<String>>()
+                .join(" · "),
+        );
+    }
+
+    Message {
+        channel: None,
+        username: None,
+        icon_url: None,
+        attachments: vec![Attachment {
+            fallback: notification.title.clone(),
+            color: notification
+                .template
+                .as_ref()
+                .map(|template| template_color_hex(template).to_string()),
+            title: notification.title.clone(),
+            title_link: notification.url.clone(),
+            text: text.join("\n\n"),
+            fields: notification
+                .fields
+                .iter()
+                .map(|field| Field {
+                    title: field.name.clone(),
+                    value: field.value.clone(),
+                    short: field.short,
+                })
+                .collect(),
+            image_url: notification.image.clone(),
+            // The footer is plain text.
+            footer: notification.note.as_deref().map(lark_md_to_plain_text),
+        }],
+    }
+}
+
+// api_key format: the whole url of the incoming webhook, url encoded as it is a query parameter
+// itself. Its `channel`, `username` and `icon_url` query parameters override those of the
+// webhook, e.g. "https://mattermost.example.com/hooks/<KEY>?channel=town-square".
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let (url, mut options) = take_url_options(&api_key, &URL_OPTIONS)?;
+    let hosts = config()
+        .mattermost
+        .as_ref()
+        .map(|mattermost| mattermost.hosts.as_slice())
+        .unwrap_or_default();
+    check_url_host(&url, hosts)?;
+    let mut message = notify(notification);
+    message.channel = options.remove("channel");
+    message.username = options.remove("username");
+    message.icon_url = options.remove("icon_url");
+    info!(
+        "Notify Mattermost: {}",
+        serde_json::to_string(&message).unwrap()
+    );
+
+    let client = Client::new();
+    let response = client.post(url).json(&message).send().await?;
+
+    match response.status() {
+        status_code if status_code.is_success() => Ok((
+            AlertStatus::Success,
+            APIResponse {
+                ok: true,
+                error: None,
+            },
+        )),
+        status_code if status_code.is_client_error() => {
+            let text = response.text().await?;
+            Ok((
+                AlertStatus::Failed,
+                APIResponse {
+                    ok: false,
+                    error: Some(match serde_json::from_str::<ErrorResponse>(&text) {
+                        Ok(error) => error.message,
+                        Err(_) => text,
+                    }),
+                },
+            ))
+        }
+        status_code => Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!(
+                "Failed to send Mattermost API request, status: {}",
+                status_code
+            ),
+        ))),
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+// Incoming webhooks answer `1` in plain text, Power Automate workflows `202 Accepted` with an empty
+// body. Anything else is the error message.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APIResponse {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
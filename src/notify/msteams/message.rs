@@ -0,0 +1,125 @@
+use serde::Serialize;
+
+pub const ADAPTIVE_CARD_CONTENT_TYPE: &str = "application/vnd.microsoft.card.adaptive";
+pub const ADAPTIVE_CARD_SCHEMA: &str = "http://adaptivecards.io/schemas/adaptive-card.json";
+pub const ADAPTIVE_CARD_VERSION: &str = "1.4";
+
+// {
+// 	"type": "message",
+// 	"attachments": [{
+// 		"contentType": "application/vnd.microsoft.card.adaptive",
+// 		"content": {
+// 			"$schema": "http://adaptivecards.io/schemas/adaptive-card.json",
+// 			"type": "AdaptiveCard",
+// 			"version": "1.4",
+// 			"body": [
+// 				{ "type": "Container", "style": "attention", "bleed": true, "items": [
+// 					{ "type": "TextBlock", "text": "Title", "size": "Large", "weight": "Bolder", "wrap": true }
+// 				] },
+// 				{ "type": "TextBlock", "text": "**bold** content", "wrap": true },
+// 				{ "type": "FactSet", "facts": [{ "title": "Size", "value": "12 GiB" }] }
+// 			],
+// 			"actions": [{ "type": "Action.OpenUrl", "title": "View", "url": "https://example.com" }]
+// 		}
+// 	}]
+// }
+#[derive(Debug, Serialize)]
+pub struct Message {
+    pub r#type: String,
+    pub attachments: Vec<Attachment>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    pub content_type: String,
+    pub content: AdaptiveCard,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MsteamsOptions {
+    pub width: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AdaptiveCard {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub r#type: String,
+    pub version: String,
+    pub msteams: MsteamsOptions,
+    pub body: Vec<Element>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<Action>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ContainerStyle {
+    Default,
+    Emphasis,
+    Good,
+    Attention,
+    Warning,
+    Accent,
+}
+
+#[derive(Debug, Serialize)]
+pub enum TextSize {
+    Small,
+    Large,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Fact {
+    pub title: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum Element {
+    Container {
+        style: ContainerStyle,
+        bleed: bool,
+        items: Vec<Element>,
+    },
+    #[serde(rename_all = "camelCase")]
+    TextBlock {
+        text: String,
+        wrap: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        size: Option<TextSize>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        weight: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        is_subtle: Option<bool>,
+    },
+    FactSet {
+        facts: Vec<Fact>,
+    },
+    Image {
+        url: String,
+        #[serde(rename = "altText")]
+        alt_text: String,
+    },
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ActionStyle {
+    Default,
+    Positive,
+    Destructive,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type")]
+pub enum Action {
+    #[serde(rename = "Action.OpenUrl")]
+    OpenUrl {
+        title: String,
+        url: String,
+        style: ActionStyle,
+    },
+}
@@ -0,0 +1,248 @@
+use reqwest::{Client, Url};
+
+use crate::{
+    common::AlertStatus,
+    notify::{
+        check_url_host,
+        feishu::card::{CardButtonType, TemplateColor},
+        msteams::{
+            api_define::APIResponse,
+            message::{
+                Action, ActionStyle, AdaptiveCard, Attachment, ContainerStyle, Element, Fact,
+                Message, MsteamsOptions, TextSize, ADAPTIVE_CARD_CONTENT_TYPE,
+                ADAPTIVE_CARD_SCHEMA, ADAPTIVE_CARD_VERSION,
+            },
+        },
+        Notification,
+    },
+};
+
+// Incoming webhooks, and Power Automate workflows old and new.
+const WEBHOOK_HOSTS: [&str; 3] = [
+    ".webhook.office.com",
+    ".logic.azure.com",
+    ".api.powerplatform.com",
+];
+
+fn container_style(template: &Option<TemplateColor>) -> ContainerStyle {
+    match template {
+        Some(
+            TemplateColor::Red
+            | TemplateColor::Error
+            | TemplateColor::Failed
+            | TemplateColor::Carmine,
+        ) => ContainerStyle::Attention,
+        Some(
+            TemplateColor::Orange
+            | TemplateColor::Warning
+            | TemplateColor::Notify
+            | TemplateColor::Yellow,
+        ) => ContainerStyle::Warning,
+        Some(TemplateColor::Green | TemplateColor::Success | TemplateColor::Completed) => {
+            ContainerStyle::Good
+        }
+        Some(TemplateColor::Grey | TemplateColor::Disabled | TemplateColor::Invalid) => {
+            ContainerStyle::Emphasis
+        }
+        Some(_) => ContainerStyle::Accent,
+        None => ContainerStyle::Default,
+    }
+}
+
+// TextBlock markdown joins single line breaks, so every line becomes a paragraph.
+fn text_block(text: &str) -> Element {
+    Element::TextBlock {
+        text: text.replace('\n', "\n\n"),
+        wrap: true,
+        size: None,
+        weight: None,
+        is_subtle: None,
+    }
+}
+
+pub fn notify(notification: &Notification) -> Message {
+    let mut body = vec![
+        Element::Container {
+            style: container_style(&notification.template),
+            bleed: true,
+            items: vec![Element::TextBlock {
+                text: notification.title.clone(),
+                wrap: true,
+                size: Some(TextSize::Large),
+                weight: Some("Bolder".to_string()),
+                is_subtle: None,
+            }],
+        },
+        text_block(&notification.content),
+    ];
+    if !notification.fields.is_empty() {
+        body.push(Element::FactSet {
+            facts: notification
+                .fields
+                .iter()
+                .map(|field| Fact {
+                    title: field.name.clone(),
+                    value: field.value.clone(),
+                })
+                .collect(),
+        });
+    }
+    // Labels get a FactSet of their own, below the fields.
+    if !notification.labels.is_empty() {
+        body.push(Element::FactSet {
+            facts: notification
+                .labels
+                .iter()
+                .map(|(name, value)| Fact {
+                    title: name.clone(),
+                    value: value.clone(),
+                })
+                .collect(),
+        });
+    }
+    if let Some(ref image) = notification.image {
+        body.push(Element::Image {
+            url: image.clone(),
+            alt_text: notification.title.clone(),
+        });
+    }
+    if let Some(ref note) = notification.note {
+        body.push(Element::TextBlock {
+            text: note.replace('\n', "\n\n"),
+            wrap: true,
+            size: Some(TextSize::Small),
+            weight: None,
+            is_subtle: Some(true),
+        });
+    }
+
+    let actions = notification
+        .url
+        .iter()
+        .map(|url| Action::OpenUrl {
+            title: "View".to_string(),
+            url: url.clone(),
+            style: ActionStyle::Positive,
+        })
+        .chain(notification.buttons.iter().map(|button| Action::OpenUrl {
+            title: button.text.clone(),
+            url: button.url.clone(),
+            style: match button.r#type {
+                Some(CardButtonType::Primary) => ActionStyle::Positive,
+                Some(CardButtonType::Danger) => ActionStyle::Destructive,
+                _ => ActionStyle::Default,
+            },
+        }))
+        .collect();
+
+    Message {
+        r#type: "message".to_string(),
+        attachments: vec![Attachment {
+            content_type: ADAPTIVE_CARD_CONTENT_TYPE.to_string(),
+            content: AdaptiveCard {
+                schema: ADAPTIVE_CARD_SCHEMA.to_string(),
+                r#type: "AdaptiveCard".to_string(),
+                version: ADAPTIVE_CARD_VERSION.to_string(),
+                msteams: MsteamsOptions {
+                    width: "Full".to_string(),
+                },
+                body,
+                actions,
+            },
+        }],
+    }
+}
+
+// api_key format: the whole url of the incoming webhook or the Power Automate workflow, url
+// encoded as it is a query parameter itself.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let url = Url::parse(&api_key)?;
+    check_url_host(&url, &WEBHOOK_HOSTS)?;
+    let message = notify(notification);
+    info!(
+        "Notify Microsoft Teams: {}",
+        serde_json::to_string(&message).unwrap()
+    );
+
+    let client = Client::new();
+    let response = client.post(url).json(&message).send().await?;
+
+    let status_code = response.status();
+    if status_code.is_server_error() {
+        return Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!(
+                "Failed to send Microsoft Teams API request, status: {}",
+                status_code
+            ),
+        )));
+    }
+
+    // Incoming webhooks also report some errors, such as a throttled request, with 200.
+    let text = response.text().await?;
+    match text.trim() {
+        "1" | "" if status_code.is_success() => Ok((
+            AlertStatus::Success,
+            APIResponse {
+                ok: true,
+                error: None,
+            },
+        )),
+        error => Ok((
+            AlertStatus::Failed,
+            APIResponse {
+                ok: false,
+                error: Some(match error {
+                    "" => status_code.to_string(),
+                    error => error.to_string(),
+                }),
+            },
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::notify::NotificationField;
+
+    #[test]
+    fn facts_of_fields_and_labels() {
+        let notification = Notification {
+            title: "Disk full".to_string(),
+            fields: vec![NotificationField {
+                name: "Size".to_string(),
+                value: "12 GiB".to_string(),
+                short: true,
+            }],
+            labels: [("env", "prod"), ("team", "db")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        };
+        let card = serde_json::to_value(notify(&notification)).unwrap();
+        let fact_sets = card["attachments"][0]["content"]["body"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|element| element["type"] == "FactSet")
+            .map(|element| element["facts"].clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            fact_sets,
+            vec![
+                json!([{ "title": "Size", "value": "12 GiB" }]),
+                json!([
+                    { "title": "env", "value": "prod" },
+                    { "title": "team", "value": "db" }
+                ]),
+            ]
+        );
+    }
+}
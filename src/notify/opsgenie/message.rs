@@ -0,0 +1,45 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+// POST /v2/alerts
+// {
+//   "message": "[Alerting] High CPU",
+//   "alias": "grafana-1-42",
+//   "description": "CPU usage is above 90%\n\nView: https://grafana.example.com/d/abc",
+//   "tags": ["instance:node-1", "critical"],
+//   "details": { "instance": "node-1" },
+//   "source": "Notify Bridge",
+//   "priority": "P1"
+// }
+#[derive(Debug, Serialize)]
+pub struct CreateAlert {
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    pub description: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub details: BTreeMap<String, String>,
+    pub source: String,
+    pub priority: Priority,
+}
+
+// POST /v2/alerts/<alias>/close?identifierType=alias
+// { "source": "Notify Bridge", "note": "[OK] High CPU" }
+#[derive(Debug, Serialize)]
+pub struct CloseAlert {
+    pub source: String,
+    pub note: String,
+}
+
+// From critical to informational.
+#[derive(Debug, Serialize)]
+pub enum Priority {
+    P1,
+    P2,
+    P3,
+    P4,
+    P5,
+}
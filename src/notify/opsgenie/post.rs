@@ -0,0 +1,271 @@
+use openssl::hash::{hash, MessageDigest};
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwest::{Client, RequestBuilder};
+
+use crate::{
+    common::AlertStatus,
+    config::config,
+    notify::{
+        lark_md_to_plain_text,
+        opsgenie::{
+            api_define::APIResponse,
+            message::{CloseAlert, CreateAlert, Priority},
+        },
+        truncate, Notification, NotificationSeverity, NotificationStatus,
+    },
+};
+
+const OPSGENIE_API: &str = "https://api.opsgenie.com";
+const SOURCE: &str = "Notify Bridge";
+const MAX_MESSAGE_LENGTH: usize = 130;
+const MAX_ALIAS_LENGTH: usize = 512;
+const MAX_DESCRIPTION_LENGTH: usize = 15000;
+const MAX_TAGS: usize = 20;
+const MAX_TAG_LENGTH: usize = 50;
+
+// Notifications without a severity are plain messages rather than alerts.
+fn severity_to_priority(severity: Option<NotificationSeverity>) -> Priority {
+    match severity {
+        Some(NotificationSeverity::Critical) => Priority::P1,
+        Some(NotificationSeverity::Error) => Priority::P2,
+        Some(NotificationSeverity::Warning) => Priority::P3,
+        Some(NotificationSeverity::Info) => Priority::P4,
+        None => Priority::P5,
+    }
+}
+
+// The fingerprint of the alert, hashed when too long for Opsgenie. Alerts with the same alias are
+// deduplicated while open, and closed together.
+fn alias(notification: &Notification) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    match notification.fingerprint {
+        Some(ref fingerprint) if fingerprint.len() > MAX_ALIAS_LENGTH => Ok(Some(
+            hash(MessageDigest::md5(), fingerprint.as_bytes())?
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect(),
+        )),
+        ref fingerprint => Ok(fingerprint.clone()),
+    }
+}
+
+fn description(notification: &Notification) -> String {
+    let mut lines = vec![lark_md_to_plain_text(&notification.content)];
+    if !notification.fields.is_empty() {
+        lines.push("".to_string());
+        lines.extend(
+            notification
+                .fields
+                .iter()
+                .map(|field| format!("{}: {}", field.name, lark_md_to_plain_text(&field.value))),
+        );
+    }
+    let links = notification
+        .url
+        .iter()
+        .map(|url| format!("View: {}", url))
+        .chain(
+            notification
+                .buttons
+                .iter()
+                .map(|button| format!("{}: {}", button.text, button.url)),
+        )
+        .collect::<Vec<String>>();
+    if !links.is_empty() {
+        lines.push("".to_string());
+        lines.extend(links);
+    }
+    if let Some(ref note) = notification.note {
+        lines.push("".to_string());
+        lines.push(lark_md_to_plain_text(note));
+    }
+    truncate(&lines.join("\n"), MAX_DESCRIPTION_LENGTH)
+}
+
+// Labels become `<name>:<value>` tags, after the severity.
+fn tags(notification: &Notification) -> Vec<String> {
+    notification
+        .severity
+        .iter()
+        .map(ToString::to_string)
+        .chain(
+            notification
+                .labels
+                .iter()
+                .map(|(name, value)| format!("{}:{}", name, value)),
+        )
+        .map(|tag| truncate(&tag, MAX_TAG_LENGTH))
+        .take(MAX_TAGS)
+        .collect()
+}
+
+pub fn notify(notification: &Notification) -> Result<CreateAlert, Box<dyn std::error::Error>> {
+    Ok(CreateAlert {
+        message: truncate(&notification.title, MAX_MESSAGE_LENGTH),
+        alias: alias(notification)?,
+        description: description(notification),
+        tags: tags(notification),
+        details: notification.labels.clone(),
+        source: SOURCE.to_string(),
+        priority: severity_to_priority(notification.severity),
+    })
+}
+
+async fn send(
+    request: RequestBuilder,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let response = request.send().await?;
+
+    // Errors such as an invalid key come with a 4xx status and a JSON message.
+    let status_code = response.status();
+    if status_code.is_server_error() {
+        return Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!(
+                "Failed to send Opsgenie API request, status: {}",
+                status_code
+            ),
+        )));
+    }
+
+    let result: APIResponse = response.json().await?;
+    match status_code.is_success() {
+        true => Ok((AlertStatus::Success, result)),
+        false => Ok((AlertStatus::Failed, result)),
+    }
+}
+
+// api_key format: "<API_KEY>", the key of an API integration. Resolved alerts close the alert of
+// their alias, so an alert without a fingerprint can not be closed. Informational notifications
+// are skipped, they would open the alert again or close it too early.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    if notification.informational {
+        return Ok((
+            AlertStatus::Success,
+            APIResponse {
+                result: Some("Skipped".to_string()),
+                message: Some("notification is informational".to_string()),
+                request_id: None,
+            },
+        ));
+    }
+    let api = config()
+        .opsgenie
+        .as_ref()
+        .map(|opsgenie| opsgenie.api_base_url.trim_end_matches('/'))
+        .unwrap_or(OPSGENIE_API);
+    let authorization = format!("GenieKey {}", api_key);
+    let client = Client::new();
+
+    let request = match notification.status {
+        NotificationStatus::Firing => {
+            let alert = notify(notification)?;
+            info!(
+                "Notify Opsgenie: {}",
+                serde_json::to_string(&alert).unwrap()
+            );
+            client
+                .post(&format!("{}/v2/alerts", api))
+                .header("Authorization", authorization)
+                .json(&alert)
+        }
+        NotificationStatus::Resolved => {
+            let alias = match alias(notification)? {
+                Some(alias) => alias,
+                None => {
+                    return Ok((
+                        AlertStatus::Failed,
+                        APIResponse {
+                            result: None,
+                            message: Some(
+                                "resolved alert has no fingerprint to close its alert".to_string(),
+                            ),
+                            request_id: None,
+                        },
+                    ))
+                }
+            };
+            let close = CloseAlert {
+                source: SOURCE.to_string(),
+                note: notification.title.clone(),
+            };
+            info!(
+                "Notify Opsgenie: close {} {}",
+                alias,
+                serde_json::to_string(&close).unwrap()
+            );
+            client
+                .post(&format!(
+                    "{}/v2/alerts/{}/close",
+                    api,
+                    utf8_percent_encode(&alias, NON_ALPHANUMERIC)
+                ))
+                .query(&[("identifierType", "alias")])
+                .header("Authorization", authorization)
+                .json(&close)
+        }
+    };
+    send(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notify::{NotificationButton, NotificationField};
+
+    #[test]
+    fn create_alert() {
+        let notification = Notification {
+            title: "t".repeat(200),
+            content: "**db-1** at 95%".to_string(),
+            url: Some("https://example.com/alert".to_string()),
+            fields: vec![NotificationField {
+                name: "Size".to_string(),
+                value: "**12** GiB".to_string(),
+                short: true,
+            }],
+            buttons: vec![NotificationButton {
+                text: "Silence".to_string(),
+                url: "https://example.com/silence".to_string(),
+                r#type: None,
+            }],
+            note: Some("by cron".to_string()),
+            severity: Some(NotificationSeverity::Critical),
+            labels: [("env", "prod"), ("description", &"d".repeat(60))]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            fingerprint: Some("grafana-1-42".to_string()),
+            ..Default::default()
+        };
+        let alert = notify(&notification).unwrap();
+        assert_eq!(alert.message.len(), MAX_MESSAGE_LENGTH);
+        assert_eq!(alert.alias.as_deref(), Some("grafana-1-42"));
+        assert_eq!(
+            alert.description,
+            "db-1 at 95%\n\nSize: 12 GiB\n\nView: https://example.com/alert\n\
+             Silence: https://example.com/silence\n\nby cron"
+        );
+        assert_eq!(
+            alert.tags,
+            vec![
+                "critical".to_string(),
+                format!("description:{}", "d".repeat(38)),
+                "env:prod".to_string(),
+            ]
+        );
+        assert!(matches!(alert.priority, Priority::P1));
+    }
+
+    #[test]
+    fn hash_long_alias() {
+        let notification = Notification {
+            fingerprint: Some("x".repeat(600)),
+            ..Default::default()
+        };
+        assert_eq!(alias(&notification).unwrap().unwrap().len(), 32);
+        assert!(matches!(severity_to_priority(None), Priority::P5));
+    }
+}
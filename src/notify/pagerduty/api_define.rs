@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+// {"status": "success", "message": "Event processed", "dedup_key": "grafana-1-42"}
+// {"status": "invalid event", "message": "Event object is invalid", "errors": ["..."]}
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APIResponse {
+    pub status: String,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dedup_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
@@ -0,0 +1,65 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::notify::NotificationSeverity;
+
+// {
+//   "routing_key": "<INTEGRATION_KEY>",
+//   "event_action": "trigger",
+//   "dedup_key": "grafana-1-42",
+//   "payload": {
+//     "summary": "[Alerting] High CPU",
+//     "source": "node-1",
+//     "severity": "critical",
+//     "timestamp": "2022-08-01T08:00:00Z",
+//     "custom_details": { "content": "CPU usage is above 90%", "instance": "node-1" }
+//   },
+//   "links": [{ "href": "https://grafana.example.com/d/abc", "text": "View" }],
+//   "images": [{ "src": "https://grafana.example.com/render/abc.png", "alt": "[Alerting] High CPU" }],
+//   "client": "Notify Bridge"
+// }
+#[derive(Debug, Serialize)]
+pub struct Event {
+    pub routing_key: String,
+    pub event_action: EventAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dedup_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<Payload>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<Link>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<Image>,
+    pub client: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventAction {
+    Trigger,
+    Resolve,
+}
+
+// Our severities are those of PagerDuty.
+#[derive(Debug, Serialize)]
+pub struct Payload {
+    pub summary: String,
+    pub source: String,
+    pub severity: NotificationSeverity,
+    pub timestamp: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom_details: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Link {
+    pub href: String,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Image {
+    pub src: String,
+    pub alt: String,
+}
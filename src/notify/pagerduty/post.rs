@@ -0,0 +1,228 @@
+use openssl::hash::{hash, MessageDigest};
+use reqwest::Client;
+
+use crate::{
+    common::AlertStatus,
+    notify::{
+        lark_md_to_plain_text,
+        pagerduty::{
+            api_define::APIResponse,
+            message::{Event, EventAction, Image, Link, Payload},
+        },
+        truncate, utc_timestamp, Notification, NotificationSeverity, NotificationStatus,
+    },
+};
+
+const PAGERDUTY_EVENTS_URL: &str = "https://events.pagerduty.com/v2/enqueue";
+const CLIENT: &str = "Notify Bridge";
+const DEFAULT_SOURCE: &str = "notify-bridge";
+const MAX_SUMMARY_LENGTH: usize = 1024;
+const MAX_DEDUP_KEY_LENGTH: usize = 255;
+// The labels naming the affected system, in order of preference.
+const SOURCE_LABELS: [&str; 6] = [
+    "host",
+    "host_name",
+    "hostname",
+    "instance",
+    "instance_name",
+    "node",
+];
+
+// The fingerprint of the alert, hashed when too long for PagerDuty, so the resolve event closes
+// the incident of the trigger event.
+fn dedup_key(notification: &Notification) -> Result<Option<String>, Box<dyn std::error::Error>> {
+    match notification.fingerprint {
+        Some(ref fingerprint) if fingerprint.len() > MAX_DEDUP_KEY_LENGTH => Ok(Some(
+            hash(MessageDigest::md5(), fingerprint.as_bytes())?
+                .iter()
+                .map(|byte| format!("{:02x}", byte))
+                .collect(),
+        )),
+        ref fingerprint => Ok(fingerprint.clone()),
+    }
+}
+
+fn payload(notification: &Notification) -> Payload {
+    let source = SOURCE_LABELS
+        .iter()
+        .find_map(|label| notification.labels.get(*label))
+        .filter(|source| !source.is_empty())
+        .cloned()
+        .unwrap_or_else(|| DEFAULT_SOURCE.to_string());
+
+    let mut custom_details = notification.labels.clone();
+    custom_details.extend(
+        notification
+            .fields
+            .iter()
+            .map(|field| (field.name.clone(), lark_md_to_plain_text(&field.value))),
+    );
+    custom_details.insert(
+        "content".to_string(),
+        lark_md_to_plain_text(&notification.content),
+    );
+
+    Payload {
+        summary: truncate(&notification.title, MAX_SUMMARY_LENGTH),
+        source,
+        severity: notification.severity.unwrap_or(NotificationSeverity::Error),
+        timestamp: utc_timestamp(),
+        custom_details,
+    }
+}
+
+// Resolved alerts resolve the incident of their dedup key, the others trigger or update one.
+pub fn notify(
+    routing_key: String,
+    notification: &Notification,
+) -> Result<Event, Box<dyn std::error::Error>> {
+    let event_action = match notification.status {
+        NotificationStatus::Firing => EventAction::Trigger,
+        NotificationStatus::Resolved => EventAction::Resolve,
+    };
+    Ok(Event {
+        routing_key,
+        event_action,
+        dedup_key: dedup_key(notification)?,
+        payload: (event_action == EventAction::Trigger).then(|| payload(notification)),
+        links: notification
+            .url
+            .iter()
+            .map(|url| Link {
+                href: url.clone(),
+                text: "View".to_string(),
+            })
+            .chain(notification.buttons.iter().map(|button| Link {
+                href: button.url.clone(),
+                text: button.text.clone(),
+            }))
+            .collect(),
+        images: notification
+            .image
+            .iter()
+            .map(|image| Image {
+                src: image.clone(),
+                alt: notification.title.clone(),
+            })
+            .collect(),
+        client: CLIENT.to_string(),
+    })
+}
+
+// api_key format: "<INTEGRATION_KEY>", the routing key of an Events API v2 integration. An alert
+// without a fingerprint can not be resolved, as its incident is unknown. Informational
+// notifications are skipped, they would trigger an incident again or resolve it too early.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    if notification.informational {
+        return Ok((
+            AlertStatus::Success,
+            APIResponse {
+                status: "skipped".to_string(),
+                message: "notification is informational".to_string(),
+                dedup_key: None,
+                errors: Vec::new(),
+            },
+        ));
+    }
+    let event = notify(api_key, notification)?;
+    if event.event_action == EventAction::Resolve && event.dedup_key.is_none() {
+        return Ok((
+            AlertStatus::Failed,
+            APIResponse {
+                status: "invalid event".to_string(),
+                message: "resolved alert has no fingerprint to resolve its incident".to_string(),
+                dedup_key: None,
+                errors: Vec::new(),
+            },
+        ));
+    }
+    info!(
+        "Notify PagerDuty: {}",
+        serde_json::to_string(&event).unwrap()
+    );
+
+    let client = Client::new();
+    let response = client
+        .post(PAGERDUTY_EVENTS_URL)
+        .json(&event)
+        .send()
+        .await?;
+
+    // Invalid events come with 400 and a JSON body, throttled ones with 429 and maybe none.
+    let status_code = response.status();
+    if status_code.is_server_error() {
+        return Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!(
+                "Failed to send PagerDuty API request, status: {}",
+                status_code
+            ),
+        )));
+    }
+
+    let text = response.text().await?;
+    let result = serde_json::from_str::<APIResponse>(&text).unwrap_or(APIResponse {
+        status: status_code.to_string(),
+        message: text,
+        dedup_key: None,
+        errors: Vec::new(),
+    });
+    match status_code.is_success() {
+        true => Ok((AlertStatus::Success, result)),
+        false => Ok((AlertStatus::Failed, result)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trigger_and_resolve_events() {
+        let notification = Notification {
+            title: "Disk full".to_string(),
+            content: "**db-1** at 95%".to_string(),
+            severity: Some(NotificationSeverity::Warning),
+            labels: [("instance", "db-1:9100"), ("env", "prod")]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            fingerprint: Some("grafana-1-42".to_string()),
+            ..Default::default()
+        };
+        let event = notify("key".to_string(), &notification).unwrap();
+        assert!(event.event_action == EventAction::Trigger);
+        assert_eq!(event.dedup_key.as_deref(), Some("grafana-1-42"));
+        let payload = event.payload.unwrap();
+        assert_eq!(payload.source, "db-1:9100");
+        assert_eq!(payload.severity, NotificationSeverity::Warning);
+        assert_eq!(payload.custom_details["content"], "db-1 at 95%");
+        assert_eq!(payload.custom_details["env"], "prod");
+
+        let notification = Notification {
+            status: NotificationStatus::Resolved,
+            labels: Default::default(),
+            fingerprint: Some("x".repeat(300)),
+            ..notification
+        };
+        let event = notify("key".to_string(), &notification).unwrap();
+        assert!(event.event_action == EventAction::Resolve);
+        assert!(event.payload.is_none());
+        assert_eq!(
+            event.dedup_key.as_deref(),
+            Some("8a4876ea55d998a5d91ed59db796af28")
+        );
+    }
+
+    #[test]
+    fn default_source() {
+        let notification = Notification {
+            labels: [("host".to_string(), "".to_string())].into_iter().collect(),
+            ..Default::default()
+        };
+        assert_eq!(payload(&notification).source, DEFAULT_SOURCE);
+    }
+}
@@ -0,0 +1,22 @@
+pub(crate) mod bark;
+pub(crate) mod gotify;
+pub(crate) mod ntfy;
+pub(crate) mod pushover;
+
+use crate::notify::Notification;
+
+// Phones show a single text, so the fields follow the content as `<name>: <value>` lines. `markup`
+// converts lark_md to the markup of the service.
+pub fn message_text(notification: &Notification, markup: fn(&str) -> String) -> String {
+    let mut lines = vec![markup(&notification.content)];
+    lines.extend(
+        notification
+            .fields
+            .iter()
+            .map(|field| format!("{}: {}", markup(&field.name), markup(&field.value))),
+    );
+    if let Some(ref note) = notification.note {
+        lines.push(markup(note));
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+// {"code": 200, "message": "success", "timestamp": 1659340800}
+// {"code": 400, "message": "failed to get device token: failed to get [xxx] device token from database", "timestamp": 1659340800}
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APIResponse {
+    pub code: u16,
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
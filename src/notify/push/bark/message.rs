@@ -0,0 +1,34 @@
+use serde::Serialize;
+
+// {
+//   "device_key": "<DEVICE_KEY>",
+//   "title": "Title",
+//   "body": "bold content",
+//   "level": "critical",
+//   "url": "https://example.com",
+//   "image": "https://example.com/graph.png",
+//   "group": "Notify Bridge"
+// }
+#[derive(Debug, Serialize)]
+pub struct Message {
+    pub device_key: String,
+    pub title: String,
+    pub body: String,
+    pub level: Level,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    pub group: String,
+}
+
+// The iOS interruption levels: critical alerts sound even when muted, time sensitive ones break
+// through focus modes and passive ones arrive silently.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Level {
+    Critical,
+    TimeSensitive,
+    Active,
+    Passive,
+}
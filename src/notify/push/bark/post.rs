@@ -0,0 +1,86 @@
+use reqwest::Client;
+
+use crate::{
+    common::AlertStatus,
+    config::config,
+    notify::{
+        lark_md_to_plain_text,
+        push::{
+            bark::{
+                api_define::APIResponse,
+                message::{Level, Message},
+            },
+            message_text,
+        },
+        Notification, NotificationSeverity, NotificationStatus,
+    },
+};
+
+const BARK_SERVER: &str = "https://api.day.app";
+const GROUP: &str = "Notify Bridge";
+
+fn level(notification: &Notification) -> Level {
+    match (notification.status, notification.severity) {
+        (NotificationStatus::Resolved, _) => Level::Active,
+        (_, Some(NotificationSeverity::Critical)) => Level::Critical,
+        (_, Some(NotificationSeverity::Error)) => Level::TimeSensitive,
+        (_, Some(NotificationSeverity::Warning)) | (_, None) => Level::Active,
+        (_, Some(NotificationSeverity::Info)) => Level::Passive,
+    }
+}
+
+pub fn notify(device_key: String, notification: &Notification) -> Message {
+    Message {
+        device_key,
+        title: notification.title.clone(),
+        body: message_text(notification, lark_md_to_plain_text),
+        level: level(notification),
+        url: notification.url.clone().or_else(|| {
+            notification
+                .buttons
+                .first()
+                .map(|button| button.url.clone())
+        }),
+        image: notification.image.clone(),
+        group: GROUP.to_string(),
+    }
+}
+
+// api_key format: "<DEVICE_KEY>", on api.day.app or the `bark.server_url` of the config.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let server = config()
+        .bark
+        .as_ref()
+        .map(|bark| bark.server_url.trim_end_matches('/'))
+        .unwrap_or(BARK_SERVER);
+    let message = notify(api_key, notification);
+    info!(
+        "Notify Bark: {} at level {:?}",
+        message.title, message.level
+    );
+
+    let client = Client::new();
+    let response = client
+        .post(&format!("{}/push", server))
+        .json(&message)
+        .send()
+        .await?;
+
+    // Errors such as an unknown device key come with a 4xx status and the same JSON body.
+    let status_code = response.status();
+    if status_code.is_server_error() {
+        return Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!("Failed to send Bark API request, status: {}", status_code),
+        )));
+    }
+
+    let response: APIResponse = response.json().await?;
+    match response.code {
+        200 => Ok((AlertStatus::Success, response)),
+        _ => Ok((AlertStatus::Failed, response)),
+    }
+}
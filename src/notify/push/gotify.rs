@@ -0,0 +1,3 @@
+pub(crate) mod api_define;
+pub(crate) mod message;
+pub(crate) mod post;
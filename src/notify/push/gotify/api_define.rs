@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+// {"id": 25, "appid": 5, "message": "...", "title": "Title", "priority": 10, "date": "2022-08-01T08:00:00Z"}
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SuccessResponse {
+    pub id: u64,
+    pub appid: u64,
+}
+
+// {"error": "Unauthorized", "errorCode": 401, "errorDescription": "you need to provide a valid access token"}
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorResponse {
+    pub error: String,
+    pub error_code: u16,
+    pub error_description: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum APIResponse {
+    Success(SuccessResponse),
+    Error(ErrorResponse),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
@@ -0,0 +1,51 @@
+use serde::Serialize;
+
+pub const MARKDOWN_CONTENT_TYPE: &str = "text/markdown";
+
+// {
+//   "title": "Title",
+//   "message": "**bold** content",
+//   "priority": 10,
+//   "extras": {
+//     "client::display": { "contentType": "text/markdown" },
+//     "client::notification": {
+//       "click": { "url": "https://example.com" },
+//       "bigImageUrl": "https://example.com/graph.png"
+//     }
+//   }
+// }
+#[derive(Debug, Serialize)]
+pub struct Message {
+    pub title: String,
+    pub message: String,
+    pub priority: u8,
+    pub extras: Extras,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Extras {
+    #[serde(rename = "client::display")]
+    pub display: Display,
+    #[serde(rename = "client::notification")]
+    pub notification: NotificationExtras,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Display {
+    pub content_type: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationExtras {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub click: Option<Click>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub big_image_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Click {
+    pub url: String,
+}
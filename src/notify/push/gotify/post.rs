@@ -0,0 +1,90 @@
+use reqwest::Client;
+
+use crate::{
+    common::AlertStatus,
+    config::config,
+    notify::{
+        lark_md_to_markdown,
+        push::{
+            gotify::{
+                api_define::APIResponse,
+                message::{
+                    Click, Display, Extras, Message, NotificationExtras, MARKDOWN_CONTENT_TYPE,
+                },
+            },
+            message_text,
+        },
+        Notification, NotificationSeverity, NotificationStatus,
+    },
+};
+
+// The Android app pops up messages from 8, plays a sound from 4 and stays silent below.
+fn priority(notification: &Notification) -> u8 {
+    match (notification.status, notification.severity) {
+        (NotificationStatus::Resolved, _) => 4,
+        (_, Some(NotificationSeverity::Critical)) => 10,
+        (_, Some(NotificationSeverity::Error)) => 8,
+        (_, Some(NotificationSeverity::Warning)) | (_, None) => 5,
+        (_, Some(NotificationSeverity::Info)) => 2,
+    }
+}
+
+pub fn notify(notification: &Notification) -> Message {
+    Message {
+        title: notification.title.clone(),
+        message: message_text(notification, lark_md_to_markdown),
+        priority: priority(notification),
+        extras: Extras {
+            display: Display {
+                content_type: MARKDOWN_CONTENT_TYPE,
+            },
+            notification: NotificationExtras {
+                click: notification.url.clone().map(|url| Click { url }),
+                big_image_url: notification.image.clone(),
+            },
+        },
+    }
+}
+
+// api_key format: "<APP_TOKEN>", the token of an application on the `gotify.server_url` of the
+// config.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let gotify = config()
+        .gotify
+        .as_ref()
+        .ok_or("gotify destination needs the `gotify` server in the config")?;
+    let message = notify(notification);
+    info!(
+        "Notify Gotify: {}",
+        serde_json::to_string(&message).unwrap()
+    );
+
+    let client = Client::new();
+    let response = client
+        .post(&format!(
+            "{}/message",
+            gotify.server_url.trim_end_matches('/')
+        ))
+        .header("X-Gotify-Key", api_key)
+        .json(&message)
+        .send()
+        .await?;
+
+    // Errors such as an unknown token come with a 4xx status and a JSON error.
+    let status_code = response.status();
+    if status_code.is_server_error() {
+        return Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!("Failed to send Gotify API request, status: {}", status_code),
+        )));
+    }
+
+    let response: APIResponse = response.json().await?;
+    match response {
+        APIResponse::Success(_) => Ok((AlertStatus::Success, response)),
+        APIResponse::Error(_) => Ok((AlertStatus::Failed, response)),
+    }
+}
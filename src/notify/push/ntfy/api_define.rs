@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+// {"id": "sPs71M8A2T", "time": 1643935928, "event": "message", "topic": "alerts", "message": "..."}
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SuccessResponse {
+    pub id: String,
+    pub topic: String,
+}
+
+// {"code": 40301, "http": 403, "error": "forbidden", "link": "https://ntfy.sh/docs/publish/#authentication"}
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ErrorResponse {
+    pub code: u32,
+    pub http: u16,
+    pub error: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum APIResponse {
+    Success(SuccessResponse),
+    Error(ErrorResponse),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
@@ -0,0 +1,29 @@
+use serde::Serialize;
+
+// The message is the body, the rest goes in headers:
+// Title: Title
+// Priority: 5
+// Tags: rotating_light,critical
+// Click: https://example.com
+// Attach: https://example.com/graph.png
+// Actions: [{"action": "view", "label": "Runbook", "url": "https://example.com/runbook"}]
+// Markdown: yes
+#[derive(Debug, Serialize)]
+pub struct Message {
+    pub title: String,
+    pub message: String,
+    pub priority: u8,
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub click: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attach: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<Action>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case", tag = "action")]
+pub enum Action {
+    View { label: String, url: String },
+}
@@ -0,0 +1,196 @@
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwest::{
+    header::{HeaderMap, HeaderValue},
+    Client,
+};
+
+use crate::{
+    common::AlertStatus,
+    config::config,
+    notify::{
+        lark_md_to_markdown,
+        push::{
+            message_text,
+            ntfy::{
+                api_define::APIResponse,
+                message::{Action, Message},
+            },
+        },
+        Notification, NotificationSeverity, NotificationStatus,
+    },
+};
+
+const NTFY_SERVER: &str = "https://ntfy.sh";
+const MAX_ACTIONS: usize = 3;
+
+// 5 is urgent, which may break through do not disturb, 3 the default.
+fn priority(notification: &Notification) -> u8 {
+    match (notification.status, notification.severity) {
+        (NotificationStatus::Resolved, _) => 3,
+        (_, Some(NotificationSeverity::Critical)) => 5,
+        (_, Some(NotificationSeverity::Error)) => 4,
+        (_, Some(NotificationSeverity::Warning)) | (_, None) => 3,
+        (_, Some(NotificationSeverity::Info)) => 2,
+    }
+}
+
+// Tags that are emoji short codes show as an emoji before the title, the others below it.
+fn tags(notification: &Notification) -> Vec<String> {
+    let emoji = match (notification.status, notification.severity) {
+        (NotificationStatus::Resolved, _) => Some("white_check_mark"),
+        (_, Some(NotificationSeverity::Critical)) => Some("rotating_light"),
+        (_, Some(NotificationSeverity::Error)) => Some("red_circle"),
+        (_, Some(NotificationSeverity::Warning)) => Some("warning"),
+        (_, Some(NotificationSeverity::Info)) => Some("information_source"),
+        (_, None) => None,
+    };
+    emoji
+        .map(str::to_string)
+        .into_iter()
+        .chain(notification.severity.iter().map(ToString::to_string))
+        .collect()
+}
+
+// ntfy decodes RFC 2047 headers, the way to send text that is not ASCII.
+fn header_value(value: &str) -> Result<HeaderValue, Box<dyn std::error::Error>> {
+    match value.is_ascii() {
+        true => Ok(HeaderValue::from_str(value)?),
+        false => Ok(HeaderValue::from_str(&format!(
+            "=?UTF-8?B?{}?=",
+            openssl::base64::encode_block(value.as_bytes())
+        ))?),
+    }
+}
+
+pub fn notify(notification: &Notification) -> Message {
+    Message {
+        title: notification.title.clone(),
+        message: message_text(notification, lark_md_to_markdown),
+        priority: priority(notification),
+        tags: tags(notification),
+        click: notification.url.clone(),
+        attach: notification.image.clone(),
+        actions: notification
+            .buttons
+            .iter()
+            .take(MAX_ACTIONS)
+            .map(|button| Action::View {
+                label: button.text.clone(),
+                url: button.url.clone(),
+            })
+            .collect(),
+    }
+}
+
+fn headers(message: &Message) -> Result<HeaderMap, Box<dyn std::error::Error>> {
+    let mut headers = HeaderMap::new();
+    headers.insert("Title", header_value(&message.title)?);
+    headers.insert("Priority", HeaderValue::from(u16::from(message.priority)));
+    headers.insert("Markdown", HeaderValue::from_static("yes"));
+    if !message.tags.is_empty() {
+        headers.insert("Tags", header_value(&message.tags.join(","))?);
+    }
+    if let Some(ref click) = message.click {
+        headers.insert("Click", header_value(click)?);
+    }
+    if let Some(ref attach) = message.attach {
+        headers.insert("Attach", header_value(attach)?);
+    }
+    if !message.actions.is_empty() {
+        headers.insert(
+            "Actions",
+            header_value(&serde_json::to_string(&message.actions)?)?,
+        );
+    }
+    if let Some(token) = config().ntfy.as_ref().and_then(|ntfy| ntfy.token.as_ref()) {
+        headers.insert("Authorization", header_value(&format!("Bearer {}", token))?);
+    }
+    Ok(headers)
+}
+
+// api_key format: "<TOPIC>", on ntfy.sh or the `ntfy.server_url` of the config.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let server = config()
+        .ntfy
+        .as_ref()
+        .map(|ntfy| ntfy.server_url.trim_end_matches('/'))
+        .unwrap_or(NTFY_SERVER);
+    let message = notify(notification);
+    info!("Notify ntfy: {}", serde_json::to_string(&message).unwrap());
+
+    let headers = headers(&message)?;
+    let client = Client::new();
+    let response = client
+        .post(&format!(
+            "{}/{}",
+            server,
+            utf8_percent_encode(&api_key, NON_ALPHANUMERIC)
+        ))
+        .headers(headers)
+        .body(message.message)
+        .send()
+        .await?;
+
+    // Errors such as a reserved topic come with a 4xx status and a JSON error.
+    let status_code = response.status();
+    if status_code.is_server_error() {
+        return Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!("Failed to send ntfy API request, status: {}", status_code),
+        )));
+    }
+
+    let response: APIResponse = response.json().await?;
+    match response {
+        APIResponse::Success(_) => Ok((AlertStatus::Success, response)),
+        APIResponse::Error(_) => Ok((AlertStatus::Failed, response)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notify::NotificationButton;
+
+    #[test]
+    fn encode_header_values() {
+        assert_eq!(header_value("Disk full").unwrap(), "Disk full");
+        assert_eq!(
+            header_value("磁盘已满").unwrap(),
+            "=?UTF-8?B?56OB55uY5bey5ruh?="
+        );
+    }
+
+    #[test]
+    fn message_headers() {
+        let notification = Notification {
+            title: "磁盘已满".to_string(),
+            content: "<font color='red'>**db-1**</font> is full".to_string(),
+            severity: Some(NotificationSeverity::Critical),
+            url: Some("https://example.com/alert".to_string()),
+            buttons: vec![NotificationButton {
+                text: "Silence".to_string(),
+                url: "https://example.com/silence".to_string(),
+                r#type: None,
+            }],
+            ..Default::default()
+        };
+        let message = notify(&notification);
+        assert_eq!(message.message, "**db-1** is full");
+
+        let headers = headers(&message).unwrap();
+        assert_eq!(headers["Title"], "=?UTF-8?B?56OB55uY5bey5ruh?=");
+        assert_eq!(headers["Priority"], "5");
+        assert_eq!(headers["Markdown"], "yes");
+        assert_eq!(headers["Tags"], "rotating_light,critical");
+        assert_eq!(headers["Click"], "https://example.com/alert");
+        assert_eq!(
+            headers["Actions"],
+            r#"[{"action":"view","label":"Silence","url":"https://example.com/silence"}]"#
+        );
+        assert!(headers.get("Attach").is_none());
+    }
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+// {"status": 1, "request": "647d2300-702c-4b38-8b2f-d56326ae460b", "receipt": "u3Skg5AFxUhkqdc8pTH6ssUuHBA5TW"}
+// {"user": "invalid", "errors": ["user identifier is invalid"], "status": 0, "request": "..."}
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APIResponse {
+    pub status: u8,
+    pub request: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub receipt: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
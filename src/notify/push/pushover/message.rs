@@ -0,0 +1,43 @@
+use serde::Serialize;
+
+// {
+//   "token": "<APP_TOKEN>",
+//   "user": "<USER_KEY>",
+//   "title": "Title",
+//   "message": "<b>bold</b> content",
+//   "html": 1,
+//   "priority": 2,
+//   "retry": 60,
+//   "expire": 3600,
+//   "tags": "grafana-1-42",
+//   "url": "https://example.com",
+//   "url_title": "View"
+// }
+#[derive(Debug, Serialize)]
+pub struct Message {
+    pub token: String,
+    pub user: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device: Option<String>,
+    pub title: String,
+    pub message: String,
+    pub html: u8,
+    pub priority: i8,
+    // Emergency messages repeat every `retry` seconds until acknowledged or `expire` seconds pass.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_title: Option<String>,
+}
+
+// { "token": "<APP_TOKEN>" }
+#[derive(Debug, Serialize)]
+pub struct CancelByTag {
+    pub token: String,
+}
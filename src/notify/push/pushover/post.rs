@@ -0,0 +1,164 @@
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use reqwest::Client;
+
+use crate::{
+    common::AlertStatus,
+    notify::{
+        lark_md_to_html,
+        push::{
+            message_text,
+            pushover::{
+                api_define::APIResponse,
+                message::{CancelByTag, Message},
+            },
+        },
+        truncate, truncate_markup, Notification, NotificationSeverity, NotificationStatus,
+    },
+};
+
+const PUSHOVER_API: &str = "https://api.pushover.net/1";
+const MAX_TITLE_LENGTH: usize = 250;
+const MAX_MESSAGE_LENGTH: usize = 1024;
+const MAX_URL_LENGTH: usize = 512;
+const EMERGENCY_PRIORITY: i8 = 2;
+const EMERGENCY_RETRY: u32 = 60;
+const EMERGENCY_EXPIRE: u32 = 3600;
+
+// Pushover only knows `<b>`, `<i>`, `<u>`, `<font>` and `<a>`, so struck text is kept plain.
+fn lark_md_to_pushover_html(text: &str) -> String {
+    lark_md_to_html(text).replace("<s>", "").replace("</s>", "")
+}
+
+// Critical alerts are emergencies, which repeat until acknowledged. Info is quiet, without sound.
+fn priority(notification: &Notification) -> i8 {
+    match (notification.status, notification.severity) {
+        (NotificationStatus::Resolved, _) => 0,
+        (_, Some(NotificationSeverity::Critical)) => EMERGENCY_PRIORITY,
+        (_, Some(NotificationSeverity::Error)) => 1,
+        (_, Some(NotificationSeverity::Warning)) | (_, None) => 0,
+        (_, Some(NotificationSeverity::Info)) => -1,
+    }
+}
+
+// Emergencies are tagged with the fingerprint of the alert, so its recovery can cancel them.
+fn tag(notification: &Notification) -> Option<String> {
+    notification
+        .fingerprint
+        .as_ref()
+        .map(|fingerprint| fingerprint.replace(',', "_"))
+}
+
+pub fn notify(
+    token: String,
+    user: String,
+    device: Option<String>,
+    notification: &Notification,
+) -> Message {
+    let priority = priority(notification);
+    let emergency = priority == EMERGENCY_PRIORITY;
+    let (url, url_title) = match notification.url {
+        Some(ref url) => (Some(url.clone()), Some("View".to_string())),
+        None => match notification.buttons.first() {
+            Some(button) => (Some(button.url.clone()), Some(button.text.clone())),
+            None => (None, None),
+        },
+    };
+
+    Message {
+        token,
+        user,
+        device,
+        title: truncate(&notification.title, MAX_TITLE_LENGTH),
+        message: truncate_markup(
+            &message_text(notification, str::to_string),
+            MAX_MESSAGE_LENGTH,
+            lark_md_to_pushover_html,
+        ),
+        html: 1,
+        priority,
+        retry: emergency.then_some(EMERGENCY_RETRY),
+        expire: emergency.then_some(EMERGENCY_EXPIRE),
+        tags: emergency.then(|| tag(notification)).flatten(),
+        url: url.filter(|url| url.len() <= MAX_URL_LENGTH),
+        url_title,
+    }
+}
+
+// Stop repeating the emergencies of a recovered alert, its notification follows anyway.
+async fn cancel_emergencies(client: &Client, token: &str, tag: &str) {
+    let result = client
+        .post(&format!(
+            "{}/receipts/cancel_by_tag/{}.json",
+            PUSHOVER_API,
+            utf8_percent_encode(tag, NON_ALPHANUMERIC)
+        ))
+        .json(&CancelByTag {
+            token: token.to_string(),
+        })
+        .send()
+        .await;
+    match result {
+        Ok(response) if response.status().is_success() => {
+            info!("Pushover emergencies of {} canceled", tag)
+        }
+        Ok(response) => warn!(
+            "Failed to cancel Pushover emergencies of {}, status: {}",
+            tag,
+            response.status()
+        ),
+        Err(err) => warn!("Failed to cancel Pushover emergencies of {}: {}", tag, err),
+    }
+}
+
+// api_key format: "<APP_TOKEN>:<USER_KEY>", or "<APP_TOKEN>:<USER_KEY>:<DEVICE>" to push to one
+// device of the user. The user key may be a group key.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let (token, user, device) = match api_key.split(':').collect::<Vec<&str>>()[..] {
+        [token, user] => (token.to_string(), user.to_string(), None),
+        [token, user, device] => (
+            token.to_string(),
+            user.to_string(),
+            Some(device.to_string()),
+        ),
+        _ => return Err("invalid Pushover api key, expect <APP_TOKEN>:<USER_KEY>".into()),
+    };
+
+    let client = Client::new();
+    if notification.status == NotificationStatus::Resolved {
+        if let Some(tag) = tag(notification) {
+            cancel_emergencies(&client, &token, &tag).await;
+        }
+    }
+
+    let message = notify(token, user, device, notification);
+    info!(
+        "Notify Pushover: {} with priority {}",
+        message.title, message.priority
+    );
+    let response = client
+        .post(&format!("{}/messages.json", PUSHOVER_API))
+        .json(&message)
+        .send()
+        .await?;
+
+    // Errors such as an invalid user key come with a 4xx status and a JSON body.
+    let status_code = response.status();
+    if status_code.is_server_error() {
+        return Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!(
+                "Failed to send Pushover API request, status: {}",
+                status_code
+            ),
+        )));
+    }
+
+    let response: APIResponse = response.json().await?;
+    match response.status {
+        1 => Ok((AlertStatus::Success, response)),
+        _ => Ok((AlertStatus::Failed, response)),
+    }
+}
@@ -0,0 +1,54 @@
+use serde::Serialize;
+
+// {
+//   "channel": "#alerts",
+//   "alias": "Notify Bridge",
+//   "attachments": [{
+//     "color": "#F54A45",
+//     "title": "Title",
+//     "title_link": "https://example.com",
+//     "text": "*bold* content",
+//     "fields": [{ "short": true, "title": "Host", "value": "node-1" }],
+//     "image_url": "https://example.com/graph.png",
+//     "actions": [{ "type": "button", "text": "Runbook", "url": "https://example.com/runbook" }]
+//   }]
+// }
+#[derive(Debug, Serialize)]
+pub struct Message {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channel: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar: Option<String>,
+    pub attachments: Vec<Attachment>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Attachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_link: Option<String>,
+    pub text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<Field>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<Action>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Field {
+    pub short: bool,
+    pub title: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum Action {
+    Button { text: String, url: String },
+}
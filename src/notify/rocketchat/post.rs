@@ -0,0 +1,114 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use reqwest::Client;
+
+use crate::{
+    common::AlertStatus,
+    config::config,
+    notify::{
+        check_url_host,
+        rocketchat::{
+            api_define::APIResponse,
+            message::{Action, Attachment, Field, Message},
+        },
+        take_url_options, template_color_hex, Notification,
+    },
+};
+
+const URL_OPTIONS: [&str; 3] = ["channel", "alias", "avatar"];
+
+static BOLD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\*\*(.+?)\*\*").unwrap());
+static STRIKE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"~~(.+?)~~").unwrap());
+
+// lark_md => Rocket.Chat markdown: `**bold**` => `*bold*`, `~~strike~~` => `~strike~`, links are
+// the same.
+pub fn lark_md_to_markdown(text: &str) -> String {
+    let text = BOLD.replace_all(text, "*$1*");
+    STRIKE.replace_all(&text, "~$1~").to_string()
+}
+
+pub fn notify(notification: &Notification) -> Message {
+    let mut text = vec![lark_md_to_markdown(&notification.content)];
+    if let Some(ref note) = notification.note {
+        text.push(format!("_{}_", lark_md_to_markdown(note)));
+    }
+
+    Message {
+        channel: None,
+        alias: None,
+        avatar: None,
+        attachments: vec![Attachment {
+            color: notification
+                .template
+                .as_ref()
+                .map(|template| template_color_hex(template).to_string()),
+            title: notification.title.clone(),
+            title_link: notification.url.clone(),
+            text: text.join("\n\n"),
+            fields: notification
+                .fields
+                .iter()
+                .map(|field| Field {
+                    short: field.short,
+                    title: field.name.clone(),
+                    value: lark_md_to_markdown(&field.value),
+                })
+                .collect(),
+            image_url: notification.image.clone(),
+            actions: notification
+                .buttons
+                .iter()
+                .map(|button| Action::Button {
+                    text: button.text.clone(),
+                    url: button.url.clone(),
+                })
+                .collect(),
+        }],
+    }
+}
+
+// api_key format: the whole url of the incoming webhook, url encoded as it is a query parameter
+// itself. Its `channel`, `alias` and `avatar` query parameters override those of the webhook,
+// e.g. "https://chat.example.com/hooks/<ID>/<TOKEN>?channel=%23alerts".
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let (url, mut options) = take_url_options(&api_key, &URL_OPTIONS)?;
+    let hosts = config()
+        .rocketchat
+        .as_ref()
+        .map(|rocketchat| rocketchat.hosts.as_slice())
+        .unwrap_or_default();
+    check_url_host(&url, hosts)?;
+    let mut message = notify(notification);
+    message.channel = options.remove("channel");
+    message.alias = options.remove("alias");
+    message.avatar = options.remove("avatar");
+    info!(
+        "Notify Rocket.Chat: {}",
+        serde_json::to_string(&message).unwrap()
+    );
+
+    let client = Client::new();
+    let response = client.post(url).json(&message).send().await?;
+
+    // Errors such as an unknown token come with a 4xx status and the same JSON body.
+    match response.status() {
+        status_code if status_code.is_server_error() => Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!(
+                "Failed to send Rocket.Chat API request, status: {}",
+                status_code
+            ),
+        ))),
+        _ => {
+            let result: APIResponse = response.json().await?;
+            match result.success {
+                true => Ok((AlertStatus::Success, result)),
+                false => Ok((AlertStatus::Failed, result)),
+            }
+        }
+    }
+}
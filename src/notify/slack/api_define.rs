@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+// Incoming webhooks answer `ok`, or an error code such as `invalid_payload` in plain text.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APIResponse {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
@@ -0,0 +1,80 @@
+use serde::Serialize;
+
+// {
+// 	"text": "Title",
+// 	"attachments": [{
+// 		"color": "#F54A45",
+// 		"blocks": [
+// 			{ "type": "header", "text": { "type": "plain_text", "text": "Title" } },
+// 			{ "type": "section", "text": { "type": "mrkdwn", "text": "*bold* content" } },
+// 			{ "type": "actions", "elements": [{
+// 				"type": "button",
+// 				"text": { "type": "plain_text", "text": "View" },
+// 				"url": "https://example.com",
+// 				"style": "primary"
+// 			}] }
+// 		]
+// 	}]
+// }
+#[derive(Debug, Serialize)]
+pub struct Message {
+    pub text: String,
+    pub attachments: Vec<Attachment>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Attachment {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    pub blocks: Vec<Block>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum Text {
+    PlainText { text: String },
+    Mrkdwn { text: String },
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ButtonStyle {
+    Primary,
+    Danger,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum Element {
+    Button {
+        text: Text,
+        url: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<ButtonStyle>,
+    },
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum Block {
+    Header {
+        text: Text,
+    },
+    // Slack rejects a section with an empty text, or without both a text and fields.
+    Section {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<Text>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        fields: Vec<Text>,
+    },
+    Context {
+        elements: Vec<Text>,
+    },
+    Image {
+        image_url: String,
+        alt_text: String,
+    },
+    Actions {
+        elements: Vec<Element>,
+    },
+}
@@ -0,0 +1,158 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use reqwest::Client;
+
+use crate::{
+    common::AlertStatus,
+    notify::{
+        feishu::card::CardButtonType,
+        slack::{
+            api_define::APIResponse,
+            message::{Attachment, Block, ButtonStyle, Element, Message, Text},
+        },
+        template_color_hex, truncate, truncate_markup, Notification,
+    },
+};
+
+const SLACK_WEBHOOK_PREFIX: &str = "https://hooks.slack.com/services/";
+const MAX_HEADER_LENGTH: usize = 150;
+const MAX_TEXT_LENGTH: usize = 3000;
+const MAX_FIELD_LENGTH: usize = 2000;
+const MAX_FIELDS: usize = 10;
+
+static LINK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[([^\]]*)\]\(([^)]*)\)").unwrap());
+static BOLD: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\*\*(.+?)\*\*").unwrap());
+static STRIKE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"~~(.+?)~~").unwrap());
+
+// lark_md => mrkdwn: `**bold**` => `*bold*`, `~~strike~~` => `~strike~`, `[text](url)` =>
+// `<url|text>`. `&`, `<` and `>` are control characters in mrkdwn and must be escaped first.
+pub fn lark_md_to_mrkdwn(text: &str) -> String {
+    let text = text
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    let text = LINK.replace_all(&text, "<$2|$1>");
+    let text = BOLD.replace_all(&text, "*$1*");
+    STRIKE.replace_all(&text, "~$1~").to_string()
+}
+
+pub fn notify(notification: &Notification) -> Message {
+    let mut blocks = vec![Block::Header {
+        text: Text::PlainText {
+            text: truncate(&notification.title, MAX_HEADER_LENGTH),
+        },
+    }];
+    let text = match notification.content.trim().is_empty() {
+        true => None,
+        false => Some(Text::Mrkdwn {
+            text: truncate_markup(&notification.content, MAX_TEXT_LENGTH, lark_md_to_mrkdwn),
+        }),
+    };
+    let fields = notification
+        .fields
+        .iter()
+        .take(MAX_FIELDS)
+        .map(|field| Text::Mrkdwn {
+            text: truncate_markup(
+                &format!("**{}**\n{}", field.name, field.value),
+                MAX_FIELD_LENGTH,
+                lark_md_to_mrkdwn,
+            ),
+        })
+        .collect::<Vec<Text>>();
+    if text.is_some() || !fields.is_empty() {
+        blocks.push(Block::Section { text, fields });
+    }
+    if let Some(ref image) = notification.image {
+        blocks.push(Block::Image {
+            image_url: image.clone(),
+            alt_text: notification.title.clone(),
+        });
+    }
+    if let Some(note) = notification
+        .note
+        .as_ref()
+        .filter(|note| !note.trim().is_empty())
+    {
+        blocks.push(Block::Context {
+            elements: vec![Text::Mrkdwn {
+                text: truncate_markup(note, MAX_TEXT_LENGTH, lark_md_to_mrkdwn),
+            }],
+        });
+    }
+
+    let elements = notification
+        .url
+        .iter()
+        .map(|url| Element::Button {
+            text: Text::PlainText {
+                text: "View".to_string(),
+            },
+            url: url.clone(),
+            style: Some(ButtonStyle::Primary),
+        })
+        .chain(notification.buttons.iter().map(|button| Element::Button {
+            text: Text::PlainText {
+                text: button.text.clone(),
+            },
+            url: button.url.clone(),
+            style: match button.r#type {
+                Some(CardButtonType::Primary) => Some(ButtonStyle::Primary),
+                Some(CardButtonType::Danger) => Some(ButtonStyle::Danger),
+                _ => None,
+            },
+        }))
+        .collect::<Vec<Element>>();
+    if !elements.is_empty() {
+        blocks.push(Block::Actions { elements });
+    }
+
+    Message {
+        text: notification.title.clone(),
+        attachments: vec![Attachment {
+            color: notification
+                .template
+                .as_ref()
+                .map(|template| template_color_hex(template).to_string()),
+            blocks,
+        }],
+    }
+}
+
+// api_key format: "T00000000/B00000000/XXXXXXXXXXXXXXXXXXXXXXXX", the path of the webhook url.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let message = notify(notification);
+    info!("Notify Slack: {}", serde_json::to_string(&message).unwrap());
+
+    let client = Client::new();
+    let response = client
+        .post(&format!("{}{}", SLACK_WEBHOOK_PREFIX, api_key))
+        .json(&message)
+        .send()
+        .await?;
+
+    match response.status() {
+        reqwest::StatusCode::OK => Ok((
+            AlertStatus::Success,
+            APIResponse {
+                ok: true,
+                error: None,
+            },
+        )),
+        status_code if status_code.is_client_error() => Ok((
+            AlertStatus::Failed,
+            APIResponse {
+                ok: false,
+                error: Some(response.text().await?),
+            },
+        )),
+        status_code => Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!("Failed to send Slack API request, status: {}", status_code),
+        ))),
+    }
+}
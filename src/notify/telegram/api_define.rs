@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MessageResult {
+    pub message_id: i64,
+}
+
+// {
+// 	"ok": true,
+// 	"result": { "message_id": 42, ... }
+// }
+// {
+// 	"ok": false,
+// 	"error_code": 400,
+// 	"description": "Bad Request: chat not found"
+// }
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APIResponse {
+    pub ok: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<MessageResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
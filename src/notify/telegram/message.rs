@@ -0,0 +1,47 @@
+use serde::Serialize;
+
+// {
+// 	"chat_id": "-1001234567890",
+// 	"message_thread_id": 7,
+// 	"text": "<b>Title</b>\ncontent",
+// 	"parse_mode": "HTML",
+// 	"link_preview_options": { "is_disabled": true },
+// 	"reply_markup": {
+// 		"inline_keyboard": [[{ "text": "View", "url": "https://example.com" }]]
+// 	}
+// }
+#[derive(Debug, Serialize)]
+pub struct Message {
+    pub chat_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    pub text: String,
+    pub parse_mode: String,
+    pub link_preview_options: LinkPreviewOptions,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_markup: Option<InlineKeyboardMarkup>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LinkPreviewOptions {
+    pub is_disabled: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InlineKeyboardButton {
+    pub text: String,
+    pub url: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct InlineKeyboardMarkup {
+    pub inline_keyboard: Vec<Vec<InlineKeyboardButton>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Photo {
+    pub chat_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_thread_id: Option<i64>,
+    pub photo: String,
+}
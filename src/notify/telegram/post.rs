@@ -0,0 +1,159 @@
+use reqwest::Client;
+use serde::Serialize;
+
+use crate::{
+    common::AlertStatus,
+    config::config,
+    notify::{
+        escape_html, lark_md_to_html,
+        telegram::{
+            api_define::APIResponse,
+            message::{
+                InlineKeyboardButton, InlineKeyboardMarkup, LinkPreviewOptions, Message, Photo,
+            },
+        },
+        truncate, Notification,
+    },
+};
+
+const TELEGRAM_API: &str = "https://api.telegram.org";
+const MAX_TEXT_LENGTH: usize = 4000;
+const BUTTONS_PER_ROW: usize = 3;
+
+// The raw length is cut before converting, so no tag is left open.
+fn text(notification: &Notification) -> String {
+    let mut lines = vec![notification.content.clone()];
+    lines.extend(
+        notification
+            .fields
+            .iter()
+            .map(|field| format!("**{}**: {}", field.name, field.value)),
+    );
+    let mut text = format!(
+        "<b>{}</b>\n{}",
+        escape_html(&notification.title),
+        lark_md_to_html(&truncate(&lines.join("\n"), MAX_TEXT_LENGTH))
+    );
+    if let Some(ref note) = notification.note {
+        text = format!("{}\n\n<i>{}</i>", text, lark_md_to_html(note));
+    }
+    text
+}
+
+pub fn notify(notification: &Notification, chat_id: String, thread_id: Option<i64>) -> Message {
+    let mut buttons = notification
+        .url
+        .iter()
+        .map(|url| InlineKeyboardButton {
+            text: "View".to_string(),
+            url: url.clone(),
+        })
+        .chain(
+            notification
+                .buttons
+                .iter()
+                .map(|button| InlineKeyboardButton {
+                    text: button.text.clone(),
+                    url: button.url.clone(),
+                }),
+        )
+        .peekable();
+
+    let mut rows = Vec::new();
+    while buttons.peek().is_some() {
+        rows.push(buttons.by_ref().take(BUTTONS_PER_ROW).collect());
+    }
+
+    Message {
+        chat_id,
+        message_thread_id: thread_id,
+        text: text(notification),
+        parse_mode: "HTML".to_string(),
+        link_preview_options: LinkPreviewOptions { is_disabled: true },
+        reply_markup: match rows.is_empty() {
+            true => None,
+            false => Some(InlineKeyboardMarkup {
+                inline_keyboard: rows,
+            }),
+        },
+    }
+}
+
+async fn call<T: Serialize>(
+    client: &Client,
+    bot_token: &str,
+    method: &str,
+    body: &T,
+) -> Result<APIResponse, Box<dyn std::error::Error>> {
+    let api = config()
+        .telegram
+        .as_ref()
+        .map(|telegram| telegram.api_base_url.trim_end_matches('/'))
+        .unwrap_or(TELEGRAM_API);
+    let response = client
+        .post(&format!("{}/bot{}/{}", api, bot_token, method))
+        .json(body)
+        .send()
+        .await?;
+
+    // Errors such as an unknown chat come with a 4xx status and a JSON description.
+    match response.status() {
+        status_code if status_code.is_server_error() => Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!(
+                "Failed to send Telegram API request, status: {}",
+                status_code
+            ),
+        ))),
+        _ => Ok(response.json().await?),
+    }
+}
+
+// api_key format: "<BOT_TOKEN>:<CHAT_ID>" or "<BOT_TOKEN>:<CHAT_ID>:<MESSAGE_THREAD_ID>" to post
+// in a forum topic. The bot token is "<BOT_ID>:<SECRET>" itself. The image follows the message on a
+// best effort basis, a failure is only logged.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let parts = api_key.split(':').collect::<Vec<&str>>();
+    let (bot_token, chat_id, thread_id) = match parts[..] {
+        [bot_id, secret, chat_id] => (format!("{}:{}", bot_id, secret), chat_id, None),
+        [bot_id, secret, chat_id, thread_id] => (
+            format!("{}:{}", bot_id, secret),
+            chat_id,
+            Some(thread_id.parse::<i64>()?),
+        ),
+        _ => return Err("invalid Telegram api key, expect <BOT_TOKEN>:<CHAT_ID>".into()),
+    };
+
+    let message = notify(notification, chat_id.to_string(), thread_id);
+    info!(
+        "Notify Telegram: {}",
+        serde_json::to_string(&message).unwrap()
+    );
+
+    let client = Client::new();
+    let response = call(&client, &bot_token, "sendMessage", &message).await?;
+    if !response.ok {
+        return Ok((AlertStatus::Failed, response));
+    }
+
+    if let Some(ref image) = notification.image {
+        let photo = Photo {
+            chat_id: chat_id.to_string(),
+            message_thread_id: thread_id,
+            photo: image.clone(),
+        };
+        match call(&client, &bot_token, "sendPhoto", &photo).await {
+            Ok(photo_response) if !photo_response.ok => warn!(
+                "Failed to send the photo `{}` to Telegram: {}",
+                image,
+                photo_response.description.unwrap_or_default()
+            ),
+            Err(err) => warn!("Failed to send the photo `{}` to Telegram: {}", image, err),
+            Ok(_) => {}
+        }
+    }
+    Ok((AlertStatus::Success, response))
+}
@@ -0,0 +1,2 @@
+pub(crate) mod api_define;
+pub(crate) mod post;
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+// Any endpoint may be behind an outbound webhook, so only the status and the raw body are kept.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APIResponse {
+    pub status_code: u16,
+    pub body: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
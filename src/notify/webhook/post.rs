@@ -0,0 +1,100 @@
+use openssl::{hash::MessageDigest, pkey::PKey, sign::Signer};
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE},
+    Client, Method,
+};
+use serde_json::Value;
+
+use crate::{
+    common::AlertStatus,
+    config::{config, OutboundWebhookConfig},
+    notify::{webhook::api_define::APIResponse, Notification},
+    template::{payload, render, render_url, render_value},
+};
+
+// The body and its content type: the payload itself, the rendered JSON template, or the rendered
+// text template.
+fn render_body(
+    webhook: &OutboundWebhookConfig,
+    payload: &Value,
+) -> Result<(String, &'static str), String> {
+    match webhook.body {
+        None => Ok((payload.to_string(), "application/json")),
+        Some(Value::String(ref template)) => Ok((render(template, payload)?, "text/plain")),
+        Some(ref template) => Ok((
+            render_value(template, payload)?.to_string(),
+            "application/json",
+        )),
+    }
+}
+
+// signature = hex(HmacSHA256(body, secret))
+fn sign(secret: &str, body: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let key = PKey::hmac(secret.as_bytes())?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+    Ok(signer
+        .sign_oneshot_to_vec(body.as_bytes())?
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect())
+}
+
+fn headers(
+    webhook: &OutboundWebhookConfig,
+    payload: &Value,
+    body: &str,
+    content_type: &'static str,
+) -> Result<HeaderMap, Box<dyn std::error::Error>> {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    for (name, value) in webhook.headers.iter() {
+        headers.insert(
+            HeaderName::from_bytes(name.as_bytes())?,
+            HeaderValue::from_str(&render(value, payload)?)?,
+        );
+    }
+    if let Some(ref secret) = webhook.secret {
+        headers.insert(
+            HeaderName::from_bytes(webhook.signature_header.as_bytes())?,
+            HeaderValue::from_str(&format!("sha256={}", sign(secret, body)?))?,
+        );
+    }
+    Ok(headers)
+}
+
+// api_key format: "<NAME>", one of `outbound_webhooks` in the config. Any 2xx status is a success.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let webhook = config()
+        .outbound_webhook(&api_key)
+        .ok_or_else(|| format!("outbound webhook `{}` is not defined", api_key))?;
+    let payload = serde_json::to_value(payload(notification))?;
+
+    let method = Method::from_bytes(webhook.method.to_uppercase().as_bytes())?;
+    let url = render_url(&webhook.url, &payload)?;
+    let (body, content_type) = render_body(webhook, &payload)?;
+    let headers = headers(webhook, &payload, &body, content_type)?;
+    info!(
+        "Notify webhook {}: {} {} {}",
+        webhook.name, method, url, body
+    );
+
+    let response = Client::new()
+        .request(method, &url)
+        .headers(headers)
+        .body(body)
+        .send()
+        .await?;
+    let status_code = response.status();
+    let result = APIResponse {
+        status_code: status_code.as_u16(),
+        body: response.text().await?,
+    };
+
+    match status_code.is_success() {
+        true => Ok((AlertStatus::Success, result)),
+        false => Ok((AlertStatus::Failed, result)),
+    }
+}
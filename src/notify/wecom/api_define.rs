@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::common::AlertStatus;
+
+// {
+// 	"errcode": 0,
+// 	"errmsg": "ok"
+// }
+#[derive(Debug, Deserialize, Serialize)]
+pub struct APIResponse {
+    pub errcode: i64,
+    pub errmsg: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct NotifyResponse {
+    pub destination: String,
+    pub status: AlertStatus,
+    pub result: APIResponse,
+}
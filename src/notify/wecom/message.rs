@@ -0,0 +1,99 @@
+use serde::Serialize;
+
+// {
+// 	"msgtype": "template_card",
+// 	"template_card": {
+// 		"card_type": "text_notice",
+// 		"source": { "desc": "Grafana", "desc_color": 2 },
+// 		"main_title": { "title": "Title" },
+// 		"sub_title_text": "content",
+// 		"horizontal_content_list": [{ "keyname": "Size", "value": "12 GiB" }],
+// 		"jump_list": [{ "type": 1, "title": "Runbook", "url": "https://example.com" }],
+// 		"card_action": { "type": 1, "url": "https://example.com" }
+// 	}
+// }
+#[derive(Debug, Serialize)]
+pub struct Message {
+    pub msgtype: MessageType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub markdown: Option<Markdown>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<Image>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_card: Option<Box<TemplateCard>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageType {
+    Text,
+    Markdown,
+    Image,
+    TemplateCard,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Text {
+    pub content: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub mentioned_mobile_list: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Markdown {
+    pub content: String,
+}
+
+// The image itself, base64 encoded, at most 2 MB before encoding.
+#[derive(Debug, Serialize)]
+pub struct Image {
+    pub base64: String,
+    pub md5: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CardSource {
+    pub desc: String,
+    // 0: grey, 1: black, 2: red, 3: green
+    pub desc_color: u8,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CardMainTitle {
+    pub title: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CardHorizontalContent {
+    pub keyname: String,
+    pub value: String,
+}
+
+// `type` 1 opens the url.
+#[derive(Debug, Serialize)]
+pub struct CardJump {
+    pub r#type: u8,
+    pub title: String,
+    pub url: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CardAction {
+    pub r#type: u8,
+    pub url: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TemplateCard {
+    pub card_type: String,
+    pub source: CardSource,
+    pub main_title: CardMainTitle,
+    pub sub_title_text: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub horizontal_content_list: Vec<CardHorizontalContent>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub jump_list: Vec<CardJump>,
+    pub card_action: CardAction,
+}
@@ -0,0 +1,349 @@
+use openssl::hash::{hash, MessageDigest};
+use reqwest::{Client, Url};
+
+use crate::{
+    common::AlertStatus,
+    config::config,
+    notify::{
+        check_url_host,
+        feishu::card::TemplateColor,
+        lark_md_to_plain_text,
+        wecom::{
+            api_define::APIResponse,
+            message::{
+                CardAction, CardHorizontalContent, CardJump, CardMainTitle, CardSource, Image,
+                Markdown, Message, MessageType, TemplateCard, Text,
+            },
+        },
+        Notification,
+    },
+};
+
+const WECOM_WEBHOOK_API: &str = "https://qyapi.weixin.qq.com/cgi-bin/webhook/send";
+const SOURCE_NAME: &str = "Notify Bridge";
+const MAX_MARKDOWN_LENGTH: usize = 4096;
+const MAX_SUB_TITLE_LENGTH: usize = 112;
+const MAX_HORIZONTAL_CONTENTS: usize = 6;
+const MAX_JUMPS: usize = 3;
+const MAX_IMAGE_SIZE: usize = 2 * 1024 * 1024;
+
+// WeCom limits are in bytes, cut at a char boundary below the limit.
+fn truncate_bytes(text: &str, max_length: usize) -> String {
+    if text.len() <= max_length {
+        return text.to_string();
+    }
+    let mut end = max_length;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    text[..end].to_string()
+}
+
+// Markdown messages only know three colors: info(green), comment(grey) and warning(orange red).
+fn markdown_color(template: &TemplateColor) -> &'static str {
+    match template {
+        TemplateColor::Green | TemplateColor::Success | TemplateColor::Completed => "info",
+        TemplateColor::Grey
+        | TemplateColor::Disabled
+        | TemplateColor::Invalid
+        | TemplateColor::Blue
+        | TemplateColor::Wathet
+        | TemplateColor::Turquoise
+        | TemplateColor::Violet
+        | TemplateColor::Purple
+        | TemplateColor::Indigo => "comment",
+        _ => "warning",
+    }
+}
+
+fn source_color(template: &Option<TemplateColor>) -> u8 {
+    match template {
+        Some(TemplateColor::Red | TemplateColor::Error | TemplateColor::Failed)
+        | Some(TemplateColor::Carmine) => 2,
+        Some(TemplateColor::Green | TemplateColor::Success | TemplateColor::Completed) => 3,
+        Some(_) => 1,
+        None => 0,
+    }
+}
+
+fn markdown(notification: &Notification) -> Message {
+    let mut lines = vec![match notification.template {
+        Some(ref template) => format!(
+            "### <font color=\"{}\">{}</font>",
+            markdown_color(template),
+            notification.title
+        ),
+        None => format!("### {}", notification.title),
+    }];
+    lines.push(notification.content.clone());
+    lines.extend(
+        notification
+            .fields
+            .iter()
+            .map(|field| format!("**{}**: {}", field.name, field.value)),
+    );
+    if let Some(ref note) = notification.note {
+        lines.extend(note.lines().map(|line| format!("> {}", line)));
+    }
+    lines.extend(
+        notification
+            .url
+            .iter()
+            .map(|url| format!("[View]({})", url)),
+    );
+    lines.extend(
+        notification
+            .buttons
+            .iter()
+            .map(|button| format!("[{}]({})", button.text, button.url)),
+    );
+
+    Message {
+        msgtype: MessageType::Markdown,
+        text: None,
+        markdown: Some(Markdown {
+            content: truncate_bytes(&lines.join("\n"), MAX_MARKDOWN_LENGTH),
+        }),
+        image: None,
+        template_card: None,
+    }
+}
+
+// Template cards only take plain text, and need a url for the card itself.
+fn template_card(notification: &Notification, content: String, url: String) -> Message {
+    let card = TemplateCard {
+        card_type: "text_notice".to_string(),
+        source: CardSource {
+            desc: SOURCE_NAME.to_string(),
+            desc_color: source_color(&notification.template),
+        },
+        main_title: CardMainTitle {
+            title: notification.title.clone(),
+        },
+        sub_title_text: content,
+        horizontal_content_list: notification
+            .fields
+            .iter()
+            .take(MAX_HORIZONTAL_CONTENTS)
+            .map(|field| CardHorizontalContent {
+                keyname: field.name.clone(),
+                value: lark_md_to_plain_text(&field.value),
+            })
+            .collect(),
+        jump_list: notification
+            .buttons
+            .iter()
+            .take(MAX_JUMPS)
+            .map(|button| CardJump {
+                r#type: 1,
+                title: button.text.clone(),
+                url: button.url.clone(),
+            })
+            .collect(),
+        card_action: CardAction { r#type: 1, url },
+    };
+
+    Message {
+        msgtype: MessageType::TemplateCard,
+        text: None,
+        markdown: None,
+        image: None,
+        template_card: Some(Box::new(card)),
+    }
+}
+
+pub fn notify(notification: &Notification) -> Message {
+    let url = notification.url.clone().or_else(|| {
+        notification
+            .buttons
+            .first()
+            .map(|button| button.url.clone())
+    });
+    // Content longer than the card subtitle goes in markdown, with the links, rather than be cut.
+    let content = lark_md_to_plain_text(&notification.content);
+    match url {
+        Some(url) if content.len() <= MAX_SUB_TITLE_LENGTH => {
+            template_card(notification, content, url)
+        }
+        _ => markdown(notification),
+    }
+}
+
+// Markdown and template cards can not mention anyone, so mentions go in a text message.
+fn mention(notification: &Notification, mobiles: Vec<String>) -> Message {
+    Message {
+        msgtype: MessageType::Text,
+        text: Some(Text {
+            content: notification.title.clone(),
+            mentioned_mobile_list: mobiles,
+        }),
+        markdown: None,
+        image: None,
+        template_card: None,
+    }
+}
+
+// The download stops as soon as the image is larger than WeCom accepts.
+async fn image(client: &Client, url: &str) -> Result<Message, Box<dyn std::error::Error>> {
+    let url = Url::parse(url)?;
+    let hosts = config()
+        .wecom
+        .as_ref()
+        .map(|wecom| wecom.image_hosts.as_slice())
+        .unwrap_or_default();
+    check_url_host(&url, hosts)?;
+    let too_large = || format!("image `{}` is larger than 2 MB", url);
+    let mut response = client.get(url.clone()).send().await?.error_for_status()?;
+    if response.content_length().unwrap_or_default() > MAX_IMAGE_SIZE as u64 {
+        return Err(too_large().into());
+    }
+    let mut image = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        image.extend_from_slice(&chunk);
+        if image.len() > MAX_IMAGE_SIZE {
+            return Err(too_large().into());
+        }
+    }
+    let md5 = hash(MessageDigest::md5(), &image)?
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+
+    Ok(Message {
+        msgtype: MessageType::Image,
+        text: None,
+        markdown: None,
+        image: Some(Image {
+            base64: openssl::base64::encode_block(&image),
+            md5,
+        }),
+        template_card: None,
+    })
+}
+
+async fn send(
+    client: &Client,
+    key: &str,
+    message: &Message,
+) -> Result<APIResponse, Box<dyn std::error::Error>> {
+    let response = client
+        .post(WECOM_WEBHOOK_API)
+        .query(&[("key", key)])
+        .json(message)
+        .send()
+        .await?;
+
+    match response.status() {
+        reqwest::StatusCode::OK => Ok(response.json().await?),
+        status_code => Err(Box::new(std::io::Error::new(
+            std::io::ErrorKind::Other,
+            format!("Failed to send WeCom API request, status: {}", status_code),
+        ))),
+    }
+}
+
+async fn send_image(
+    client: &Client,
+    key: &str,
+    url: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let message = image(client, url).await?;
+    if let Some(ref image) = message.image {
+        info!("Notify WeCom: image with md5 {}", image.md5);
+    }
+    let response = send(client, key, &message).await?;
+    match response.errcode {
+        0 => Ok(()),
+        _ => Err(response.errmsg.into()),
+    }
+}
+
+// api_key format: "<KEY>" or "<KEY>:<MOBILE>|<MOBILE>" to mention members, "@all" for everyone.
+// The mention follows the message itself, and the first failure is returned. The image comes
+// last and on a best effort basis, a failure is only logged.
+pub async fn post(
+    api_key: String,
+    notification: &Notification,
+) -> Result<(AlertStatus, APIResponse), Box<dyn std::error::Error>> {
+    let (key, mobiles) = match api_key.split_once(':') {
+        Some((key, mobiles)) => (
+            key.to_string(),
+            mobiles
+                .split('|')
+                .map(str::to_string)
+                .collect::<Vec<String>>(),
+        ),
+        None => (api_key, vec![]),
+    };
+
+    let client = Client::new();
+    let mut messages = vec![notify(notification)];
+    if !mobiles.is_empty() {
+        messages.push(mention(notification, mobiles));
+    }
+
+    let mut result = None;
+    for message in messages {
+        info!("Notify WeCom: {}", serde_json::to_string(&message).unwrap());
+        let response = send(&client, &key, &message).await?;
+        if response.errcode != 0 {
+            return Ok((AlertStatus::Failed, response));
+        }
+        result.get_or_insert(response);
+    }
+
+    if let Some(ref url) = notification.image {
+        if let Err(err) = send_image(&client, &key, url).await {
+            warn!("Failed to send the image `{}` to WeCom: {}", url, err);
+        }
+    }
+    Ok((AlertStatus::Success, result.unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notify::NotificationButton;
+
+    #[test]
+    fn truncate_at_char_boundary() {
+        assert_eq!(truncate_bytes("abc", 3), "abc");
+        assert_eq!(truncate_bytes("告警内容", 7), "告警");
+        assert_eq!(truncate_bytes("告警内容", 6), "告警");
+    }
+
+    #[test]
+    fn card_or_markdown() {
+        let notification = Notification {
+            title: "CPU high".to_string(),
+            content: "**db-1** at 95%".to_string(),
+            buttons: vec![NotificationButton {
+                text: "Silence".to_string(),
+                url: "https://example.com/silence".to_string(),
+                r#type: None,
+            }],
+            ..Default::default()
+        };
+        let message = notify(&notification);
+        let card = message.template_card.unwrap();
+        assert_eq!(card.sub_title_text, "db-1 at 95%");
+        assert_eq!(card.card_action.url, "https://example.com/silence");
+
+        let notification = Notification {
+            content: "告警".repeat(20),
+            url: Some("https://example.com/alert".to_string()),
+            template: Some(TemplateColor::Red),
+            ..notification
+        };
+        let message = notify(&notification);
+        assert!(message.template_card.is_none());
+        assert_eq!(
+            message.markdown.unwrap().content,
+            format!(
+                "### <font color=\"warning\">CPU high</font>\n{}\n\
+                 [View](https://example.com/alert)\n[Silence](https://example.com/silence)",
+                "告警".repeat(20)
+            )
+        );
+    }
+}
@@ -0,0 +1,224 @@
+pub(crate) mod json_path;
+
+use std::collections::BTreeMap;
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    notify::{utc_timestamp, Notification, NotificationSeverity, NotificationStatus},
+    template::json_path::select,
+};
+
+// Unreserved characters of RFC 3986 are left as is.
+const URL_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+// The normalized alert, sent as is without a body template, and seen by the templates as `$`:
+// {
+//   "title": "[FIRING:1] HighCPU",
+//   "status": "firing",
+//   "severity": "critical",
+//   "url": "https://grafana.example.com/alerting/list",
+//   "content": "**node-1** CPU usage is above 90%",
+//   "image": null,
+//   "labels": { "alertname": "HighCPU", "instance": "node-1" },
+//   "fingerprint": "grafana-1-42",
+//   "timestamp": "2022-08-01T08:00:00Z",
+//   "raw": { "receiver": "notify-bridge", "status": "firing", "alerts": [] }
+// }
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Payload {
+    pub title: String,
+    pub status: NotificationStatus,
+    pub severity: Option<NotificationSeverity>,
+    pub url: Option<String>,
+    pub content: String,
+    pub image: Option<String>,
+    pub labels: BTreeMap<String, String>,
+    pub fingerprint: Option<String>,
+    pub timestamp: String,
+    pub raw: Option<Value>,
+}
+
+pub fn payload(notification: &Notification) -> Payload {
+    Payload {
+        title: notification.title.clone(),
+        status: notification.status,
+        severity: notification.severity,
+        url: notification.url.clone(),
+        content: notification.content.clone(),
+        image: notification.image.clone(),
+        labels: notification.labels.clone(),
+        fingerprint: notification.fingerprint.clone(),
+        timestamp: utc_timestamp(),
+        raw: notification.raw.clone(),
+    }
+}
+
+pub fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => "".to_string(),
+        Value::String(s) => s.clone(),
+        _ => value.to_string(),
+    }
+}
+
+fn select_to_string(body: &Value, path: &str) -> Result<String, String> {
+    Ok(select(body, path)?
+        .into_iter()
+        .map(value_to_string)
+        .collect::<Vec<String>>()
+        .join(", "))
+}
+
+// Render a bare JSONPath, or a template with `{{ <JSONPath> }}` placeholders.
+pub fn render(expression: &str, body: &Value) -> Result<String, String> {
+    render_with(expression, body, str::to_string)
+}
+
+// Placeholders in a URL are percent-encoded, a bare JSONPath is the URL itself.
+pub fn render_url(expression: &str, body: &Value) -> Result<String, String> {
+    render_with(expression, body, |value| {
+        utf8_percent_encode(value, URL_ENCODE_SET).to_string()
+    })
+}
+
+fn render_with(
+    expression: &str,
+    body: &Value,
+    escape: fn(&str) -> String,
+) -> Result<String, String> {
+    if !expression.contains("{{") {
+        return match expression.trim_start().starts_with('$') {
+            true => select_to_string(body, expression),
+            false => Ok(expression.to_string()),
+        };
+    }
+
+    let mut rendered = String::new();
+    let mut rest = expression;
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..]
+            .find("}}")
+            .ok_or_else(|| format!("unclosed `{{{{` in `{}`", expression))?;
+        rendered.push_str(&rest[..start]);
+        rendered.push_str(&escape(&select_to_string(
+            body,
+            &rest[start + 2..start + end],
+        )?));
+        rest = &rest[start + end + 2..];
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+// A string that is only a bare JSONPath or a single `{{ <JSONPath> }}` placeholder is replaced by
+// the selected JSON value, so objects and numbers keep their type. Other strings are rendered.
+pub fn render_value(template: &Value, payload: &Value) -> Result<Value, String> {
+    match template {
+        Value::String(expression) => {
+            let trimmed = expression.trim();
+            let path = match trimmed
+                .strip_prefix("{{")
+                .and_then(|rest| rest.strip_suffix("}}"))
+            {
+                Some(path) if !path.contains("{{") => Some(path),
+                Some(_) => None,
+                None => trimmed.starts_with('$').then_some(trimmed),
+            };
+            match path {
+                Some(path) => {
+                    let mut values = select(payload, path)?;
+                    Ok(match values.len() {
+                        0 => Value::Null,
+                        1 => values.remove(0).clone(),
+                        _ => Value::Array(values.into_iter().cloned().collect()),
+                    })
+                }
+                None => Ok(Value::String(render(expression, payload)?)),
+            }
+        }
+        Value::Array(array) => Ok(Value::Array(
+            array
+                .iter()
+                .map(|item| render_value(item, payload))
+                .collect::<Result<_, _>>()?,
+        )),
+        Value::Object(map) => Ok(Value::Object(
+            map.iter()
+                .map(|(k, v)| Ok((k.clone(), render_value(v, payload)?)))
+                .collect::<Result<_, String>>()?,
+        )),
+        _ => Ok(template.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn render_expressions() {
+        let body = json!({
+            "action": "created",
+            "issue": { "title": "Boom", "count": 3, "tags": ["a", "b"], "none": null }
+        });
+        assert_eq!(render("$.issue.title", &body).unwrap(), "Boom");
+        assert_eq!(render("$.issue.count", &body).unwrap(), "3");
+        assert_eq!(render("$.issue.tags[*]", &body).unwrap(), "a, b");
+        assert_eq!(render("$.issue.none", &body).unwrap(), "");
+        assert_eq!(render("plain text", &body).unwrap(), "plain text");
+        assert_eq!(
+            render(
+                "[{{ $.action }}] {{$.issue.title}} x{{ $.issue.count }}",
+                &body
+            )
+            .unwrap(),
+            "[created] Boom x3"
+        );
+        assert!(render("{{ $.action ", &body).is_err());
+        assert!(render("{{ action }}", &body).is_err());
+    }
+
+    #[test]
+    fn render_url_placeholders() {
+        let body =
+            json!({ "team": "db ops", "path": "a/b?c=d&e", "url": "https://example.com/x?y=1" });
+        assert_eq!(
+            render_url("https://hooks.example.com/{{ $.team }}/{{ $.path }}", &body).unwrap(),
+            "https://hooks.example.com/db%20ops/a%2Fb%3Fc%3Dd%26e"
+        );
+        assert_eq!(
+            render_url("$.url", &body).unwrap(),
+            "https://example.com/x?y=1"
+        );
+        assert_eq!(render("{{ $.path }}", &body).unwrap(), "a/b?c=d&e");
+    }
+
+    #[test]
+    fn render_json_templates() {
+        let body = json!({ "title": "Boom", "labels": { "env": "prod" }, "count": 3 });
+        let template = json!({
+            "text": "{{ $.title }} x{{ $.count }}",
+            "labels": "{{ $.labels }}",
+            "count": "$.count",
+            "tags": ["$.labels.env", true]
+        });
+        assert_eq!(
+            render_value(&template, &body).unwrap(),
+            json!({
+                "text": "Boom x3",
+                "labels": { "env": "prod" },
+                "count": 3,
+                "tags": ["prod", true]
+            })
+        );
+    }
+}
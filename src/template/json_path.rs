@@ -0,0 +1,145 @@
+use serde_json::Value;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Segment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+fn parse(path: &str) -> Result<Vec<Segment>, String> {
+    let path = path.trim();
+    let mut rest = path
+        .strip_prefix('$')
+        .ok_or_else(|| format!("JSONPath `{}` should start with `$`", path))?;
+    let mut segments = Vec::new();
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            let end = after.find(|c| c == '.' || c == '[').unwrap_or(after.len());
+            let key = &after[..end];
+            if key.is_empty() {
+                return Err(format!("empty key in JSONPath `{}`", path));
+            }
+            segments.push(match key {
+                "*" => Segment::Wildcard,
+                _ => Segment::Key(key.to_string()),
+            });
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let end = after
+                .find(']')
+                .ok_or_else(|| format!("unclosed `[` in JSONPath `{}`", path))?;
+            let selector = after[..end].trim();
+            segments.push(if selector == "*" {
+                Segment::Wildcard
+            } else if let Ok(index) = selector.parse::<usize>() {
+                Segment::Index(index)
+            } else {
+                let key = selector
+                    .strip_prefix('\'')
+                    .and_then(|s| s.strip_suffix('\''))
+                    .or_else(|| selector.strip_prefix('"').and_then(|s| s.strip_suffix('"')))
+                    .ok_or_else(|| format!("invalid selector `{}` in `{}`", selector, path))?;
+                Segment::Key(key.to_string())
+            });
+            rest = &after[end + 1..];
+        } else {
+            return Err(format!("unexpected `{}` in JSONPath `{}`", rest, path));
+        }
+    }
+
+    Ok(segments)
+}
+
+// Evaluate the subset of JSONPath that covers webhook payloads:
+// `$`, `.key`, `['key']`, `[0]`, `.*` and `[*]`.
+pub fn select<'a>(value: &'a Value, path: &str) -> Result<Vec<&'a Value>, String> {
+    let mut current = vec![value];
+    for segment in parse(path)? {
+        current = current
+            .into_iter()
+            .flat_map(|value| match (&segment, value) {
+                (Segment::Key(key), Value::Object(map)) => map.get(key).into_iter().collect(),
+                (Segment::Index(index), Value::Array(array)) => {
+                    array.get(*index).into_iter().collect()
+                }
+                (Segment::Wildcard, Value::Array(array)) => array.iter().collect(),
+                (Segment::Wildcard, Value::Object(map)) => map.values().collect(),
+                _ => Vec::new(),
+            })
+            .collect();
+    }
+    Ok(current)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_segments() {
+        assert_eq!(parse("$").unwrap(), vec![]);
+        assert_eq!(
+            parse(" $.a['b c'][\"d\"][2].*[*] ").unwrap(),
+            vec![
+                Segment::Key("a".to_string()),
+                Segment::Key("b c".to_string()),
+                Segment::Key("d".to_string()),
+                Segment::Index(2),
+                Segment::Wildcard,
+                Segment::Wildcard,
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(parse("a.b").is_err());
+        assert!(parse("$.").is_err());
+        assert!(parse("$.a..b").is_err());
+        assert!(parse("$[0").is_err());
+        assert!(parse("$[b]").is_err());
+        assert!(parse("$a").is_err());
+    }
+
+    #[test]
+    fn select_values() {
+        let body = json!({
+            "data": {
+                "issue": { "title": "Boom", "tags": ["a", "b"] },
+                "dotted.key": 1
+            },
+            "items": [{ "id": 1 }, { "id": 2 }, { "name": "x" }]
+        });
+        assert_eq!(select(&body, "$").unwrap(), vec![&body]);
+        assert_eq!(
+            select(&body, "$.data.issue.title").unwrap(),
+            vec![&json!("Boom")]
+        );
+        assert_eq!(
+            select(&body, "$.data['dotted.key']").unwrap(),
+            vec![&json!(1)]
+        );
+        assert_eq!(
+            select(&body, "$.data.issue.tags[1]").unwrap(),
+            vec![&json!("b")]
+        );
+        assert_eq!(
+            select(&body, "$.items[*].id").unwrap(),
+            vec![&json!(1), &json!(2)]
+        );
+        assert_eq!(select(&body, "$.data.issue.*").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn select_missing() {
+        let body = json!({ "a": [1], "b": "text" });
+        assert!(select(&body, "$.missing").unwrap().is_empty());
+        assert!(select(&body, "$.a[5]").unwrap().is_empty());
+        assert!(select(&body, "$.b.c").unwrap().is_empty());
+        assert!(select(&body, "$.b[0]").unwrap().is_empty());
+    }
+}